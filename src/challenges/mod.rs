@@ -0,0 +1,300 @@
+use crate::{
+    enums::{ChallengeLevel, Region},
+    proxy::{request, Result},
+    types::{ApexPlayerInfo, ChallengeConfigInfo, ChallengePlayerInfo, Puuid},
+    Client, ClientContext,
+};
+use std::collections::HashMap;
+use url::Url;
+
+impl Client {
+    /// Access the challenges namespace that contains challenges related API methods.
+    ///
+    /// [Riot API documentation](https://developer.riotgames.com/apis#lol-challenges-v1).
+    ///
+    /// Use this method instead of manually constructing a `Challenges` struct.
+    /// # Example
+    ///
+    /// ```rust
+    /// # use league_of_legends_client::{Client, ClientOptions};
+    /// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    ///
+    /// // Access the challenges API methods.
+    /// let challenges_api = client.challenges();
+    /// ```
+    pub fn challenges(&self) -> Challenges<'_> {
+        Challenges {
+            context: &self.context,
+        }
+    }
+}
+
+/// Namespace for the challenges related APIs.
+///
+/// [Riot API documentation](https://developer.riotgames.com/apis#lol-challenges-v1).
+///
+/// Do not create this struct manually. The preferred method is to call `client.challenges()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::Region,
+/// #    types::Puuid,
+/// #    Client, ClientOptions,
+/// #    Error,
+/// # };
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+///
+/// // Access the challenges API methods.
+/// let mut challenge_configs = client
+///     .challenges()
+///     .get_all_challenge_configs(Region::NorthAmerica)
+///     .await;
+///
+/// // Alternatively use this to save some characters.
+/// let challenges_api = client.challenges();
+///
+/// challenge_configs = challenges_api
+///     .get_all_challenge_configs(Region::NorthAmerica)
+///     .await;
+/// let player_data = challenges_api
+///     .get_player_data(Region::NorthAmerica, Puuid::from("EXAMPLE_PUUID"))
+///     .await;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Challenges<'a> {
+    context: &'a ClientContext,
+}
+
+impl Challenges<'_> {
+    /// List of all basic challenge configuration information (includes all translations for names and descriptions).
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#lol-challenges-v1/GET_getAllChallengeConfigs).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let challenge_configs = client
+    ///     .challenges()
+    ///     .get_all_challenge_configs(Region::NorthAmerica)
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_challenge_configs(
+        &self,
+        region: Region,
+    ) -> Result<Vec<ChallengeConfigInfo>> {
+        let url = Url::parse(&format!(
+            "https://{}/lol/challenges/v1/challenges/config",
+            region
+        ))?;
+
+        request::<Vec<ChallengeConfigInfo>>(url.as_str(), self.context).await
+    }
+
+    /// Map of level to percentile of players who have achieved it, keyed by challenge ID.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#lol-challenges-v1/GET_getAllChallengePercentiles).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let percentiles = client
+    ///     .challenges()
+    ///     .get_all_challenge_percentiles(Region::NorthAmerica)
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_challenge_percentiles(
+        &self,
+        region: Region,
+    ) -> Result<HashMap<i64, HashMap<ChallengeLevel, f64>>> {
+        let url = Url::parse(&format!(
+            "https://{}/lol/challenges/v1/challenges/percentiles",
+            region
+        ))?;
+
+        request::<HashMap<i64, HashMap<ChallengeLevel, f64>>>(url.as_str(), self.context).await
+    }
+
+    /// Get challenge configuration for a given challenge ID.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#lol-challenges-v1/GET_getChallengeConfigs).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let challenge_config = client
+    ///     .challenges()
+    ///     .get_challenge_config(Region::NorthAmerica, 0)
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_challenge_config(
+        &self,
+        region: Region,
+        challenge_id: i64,
+    ) -> Result<ChallengeConfigInfo> {
+        let url = Url::parse(&format!(
+            "https://{}/lol/challenges/v1/challenges/{}/config",
+            region, challenge_id
+        ))?;
+
+        request::<ChallengeConfigInfo>(url.as_str(), self.context).await
+    }
+
+    /// Get the top players for a challenge at the given level.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#lol-challenges-v1/GET_getChallengeLeaderboards).
+    ///
+    /// Leaderboards are only available for `ChallengeLevel::Master`, `ChallengeLevel::Grandmaster` and `ChallengeLevel::Challenger`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::{ChallengeLevel, Region},
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let leaderboard = client
+    ///     .challenges()
+    ///     .get_challenge_leaderboard(Region::NorthAmerica, 0, ChallengeLevel::Challenger, Some(10))
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_challenge_leaderboard(
+        &self,
+        region: Region,
+        challenge_id: i64,
+        level: ChallengeLevel,
+        limit: Option<i32>,
+    ) -> Result<Vec<ApexPlayerInfo>> {
+        let mut url = Url::parse(&format!(
+            "https://{}/lol/challenges/v1/challenges/{}/leaderboards/by-level/{}",
+            region, challenge_id, level
+        ))?;
+
+        if let Some(limit) = limit {
+            url.query_pairs_mut()
+                .append_pair("limit", &limit.to_string());
+        }
+
+        request::<Vec<ApexPlayerInfo>>(url.as_str(), self.context).await
+    }
+
+    /// Map of level to percentile of players who have achieved it for a given challenge ID.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#lol-challenges-v1/GET_getChallengePercentiles).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let percentiles = client
+    ///     .challenges()
+    ///     .get_challenge_percentiles(Region::NorthAmerica, 0)
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_challenge_percentiles(
+        &self,
+        region: Region,
+        challenge_id: i64,
+    ) -> Result<HashMap<ChallengeLevel, f64>> {
+        let url = Url::parse(&format!(
+            "https://{}/lol/challenges/v1/challenges/{}/percentiles",
+            region, challenge_id
+        ))?;
+
+        request::<HashMap<ChallengeLevel, f64>>(url.as_str(), self.context).await
+    }
+
+    /// Returns player information with list of all progressed challenges.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#lol-challenges-v1/GET_getPlayerData).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    types::Puuid,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let player_data = client
+    ///     .challenges()
+    ///     .get_player_data(Region::NorthAmerica, Puuid::from("EXAMPLE_PUUID"))
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_player_data(
+        &self,
+        region: Region,
+        puuid: Puuid,
+    ) -> Result<ChallengePlayerInfo> {
+        let url = Url::parse(&format!(
+            "https://{}/lol/challenges/v1/player-data/{}",
+            region, puuid
+        ))?;
+
+        request::<ChallengePlayerInfo>(url.as_str(), self.context).await
+    }
+}
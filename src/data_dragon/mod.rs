@@ -0,0 +1,258 @@
+use crate::{
+    proxy::{request_without_token, Result},
+    static_data::DataDragonList,
+    types::{
+        StaticChampion, StaticItem, StaticMap, StaticQueue, StaticRuneStyle, StaticSummonerSpell,
+    },
+    StaticData,
+};
+use reqwest::Client as HttpClient;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+const DEFAULT_BASE_URL: &str = "https://ddragon.leagueoflegends.com";
+const DEFAULT_DOCS_URL: &str = "https://static.developer.riotgames.com/docs/lol";
+
+/// An asynchronous client for [Data Dragon](https://developer.riotgames.com/docs/lol#data-dragon), the static
+/// data for champions, items, runes and summoner spells.
+///
+/// Data Dragon does not require an api key and is not rate limited, so it is not part of the `Client`. Queue and
+/// map metadata is fetched from the Riot developer portal documentation alongside it.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{DataDragon, Error};
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let data_dragon = DataDragon::new();
+///
+/// // Alternatively point it at a local mirror.
+/// let mirror = DataDragon::with_base_url("http://localhost:8080");
+///
+/// let versions = data_dragon.get_versions().await;
+/// let champions = data_dragon.get_champions("11.1.1", "en_US").await;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct DataDragon {
+    http_client: HttpClient,
+    base_url: String,
+    docs_url: String,
+}
+
+impl Default for DataDragon {
+    fn default() -> Self {
+        DataDragon::new()
+    }
+}
+
+impl DataDragon {
+    /// Creates a new instance of `DataDragon` using `https://ddragon.leagueoflegends.com`.
+    pub fn new() -> Self {
+        DataDragon {
+            http_client: HttpClient::new(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            docs_url: DEFAULT_DOCS_URL.to_string(),
+        }
+    }
+
+    /// Creates a new instance of `DataDragon` that fetches from the given base url, such as a local mirror.
+    ///
+    /// The mirror is expected to serve the Data Dragon paths (`/api/versions.json`, `/cdn/...`) as well as the
+    /// queue and map documentation under `/docs/lol/queues.json` and `/docs/lol/maps.json`.
+    pub fn with_base_url<T: Into<String>>(base_url: T) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        DataDragon {
+            http_client: HttpClient::new(),
+            docs_url: format!("{}/docs/lol", base_url),
+            base_url,
+        }
+    }
+
+    async fn get_data<T: DeserializeOwned>(
+        &self,
+        version: &str,
+        locale: &str,
+        file: &str,
+    ) -> Result<T> {
+        let url = Url::parse(&format!(
+            "{}/cdn/{}/data/{}/{}",
+            self.base_url, version, locale, file
+        ))?;
+
+        request_without_token::<T>(url.as_str(), &self.http_client).await
+    }
+
+    /// Get all game versions, newest first.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{DataDragon, Error};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let versions = DataDragon::new().get_versions().await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_versions(&self) -> Result<Vec<String>> {
+        let url = Url::parse(&format!("{}/api/versions.json", self.base_url))?;
+
+        request_without_token::<Vec<String>>(url.as_str(), &self.http_client).await
+    }
+
+    /// Get all champions for a game version and locale.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{DataDragon, Error};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let champions = DataDragon::new().get_champions("11.1.1", "en_US").await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_champions(&self, version: &str, locale: &str) -> Result<Vec<StaticChampion>> {
+        let champions = self
+            .get_data::<DataDragonList<StaticChampion>>(version, locale, "champion.json")
+            .await?;
+
+        Ok(champions.into_vec())
+    }
+
+    /// Get all items for a game version and locale.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{DataDragon, Error};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let items = DataDragon::new().get_items("11.1.1", "en_US").await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_items(&self, version: &str, locale: &str) -> Result<Vec<StaticItem>> {
+        let items = self
+            .get_data::<DataDragonList<StaticItem>>(version, locale, "item.json")
+            .await?;
+
+        Ok(items.into_items())
+    }
+
+    /// Get all rune styles, each containing their runes, for a game version and locale.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{DataDragon, Error};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let rune_styles = DataDragon::new().get_runes("11.1.1", "en_US").await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_runes(&self, version: &str, locale: &str) -> Result<Vec<StaticRuneStyle>> {
+        self.get_data::<Vec<StaticRuneStyle>>(version, locale, "runesReforged.json")
+            .await
+    }
+
+    /// Get all summoner spells for a game version and locale.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{DataDragon, Error};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let summoner_spells = DataDragon::new().get_summoner_spells("11.1.1", "en_US").await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_summoner_spells(
+        &self,
+        version: &str,
+        locale: &str,
+    ) -> Result<Vec<StaticSummonerSpell>> {
+        let summoner_spells = self
+            .get_data::<DataDragonList<StaticSummonerSpell>>(version, locale, "summoner.json")
+            .await?;
+
+        Ok(summoner_spells.into_vec())
+    }
+
+    /// Get all queues.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{DataDragon, Error};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let queues = DataDragon::new().get_queues().await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_queues(&self) -> Result<Vec<StaticQueue>> {
+        let url = Url::parse(&format!("{}/queues.json", self.docs_url))?;
+
+        request_without_token::<Vec<StaticQueue>>(url.as_str(), &self.http_client).await
+    }
+
+    /// Get all maps.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{DataDragon, Error};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let maps = DataDragon::new().get_maps().await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_maps(&self) -> Result<Vec<StaticMap>> {
+        let url = Url::parse(&format!("{}/maps.json", self.docs_url))?;
+
+        request_without_token::<Vec<StaticMap>>(url.as_str(), &self.http_client).await
+    }
+
+    /// Get the champions, items, runes, summoner spells, queues and maps for a game version and locale as
+    /// `StaticData`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{DataDragon, Error};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let static_data = DataDragon::new().get_static_data("11.1.1", "en_US").await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_static_data(&self, version: &str, locale: &str) -> Result<StaticData> {
+        Ok(StaticData::new(
+            version.to_string(),
+            locale.to_string(),
+            self.get_champions(version, locale).await?,
+            self.get_items(version, locale).await?,
+            self.get_runes(version, locale).await?,
+            self.get_summoner_spells(version, locale).await?,
+            self.get_queues().await?,
+            self.get_maps().await?,
+        ))
+    }
+}
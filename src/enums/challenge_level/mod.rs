@@ -0,0 +1,29 @@
+fallback_enum! {
+    /// Challenge levels in league of legends, ordered from lowest to highest.
+    ///
+    /// Levels that are not known to this crate are kept as `ChallengeLevel::Unknown` and ordered after the known levels.
+    #[derive(Ord, PartialOrd)]
+    pub enum ChallengeLevel {
+        /// NONE, the challenge has not been progressed yet.
+        #[strum(serialize = "NONE")]
+        None,
+        #[strum(serialize = "IRON")]
+        Iron,
+        #[strum(serialize = "BRONZE")]
+        Bronze,
+        #[strum(serialize = "SILVER")]
+        Silver,
+        #[strum(serialize = "GOLD")]
+        Gold,
+        #[strum(serialize = "PLATINUM")]
+        Platinum,
+        #[strum(serialize = "DIAMOND")]
+        Diamond,
+        #[strum(serialize = "MASTER")]
+        Master,
+        #[strum(serialize = "GRANDMASTER")]
+        Grandmaster,
+        #[strum(serialize = "CHALLENGER")]
+        Challenger,
+    }
+}
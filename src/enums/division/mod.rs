@@ -1,18 +1,20 @@
-use strum_macros::Display;
-
-/// Ranked divisions in league of legends.
-#[derive(Clone, Display)]
-pub enum Division {
-    /// I
-    #[strum(serialize = "I")]
-    One,
-    /// II
-    #[strum(serialize = "II")]
-    Two,
-    /// III
-    #[strum(serialize = "III")]
-    Three,
-    /// IV
-    #[strum(serialize = "IV")]
-    Four,
+fallback_enum! {
+    /// Ranked divisions in league of legends, ordered from lowest (IV) to highest (I).
+    ///
+    /// Divisions that are not known to this crate are kept as `Division::Unknown` and ordered after the known divisions.
+    #[derive(Ord, PartialOrd)]
+    pub enum Division {
+        /// IV
+        #[strum(serialize = "IV")]
+        Four,
+        /// III
+        #[strum(serialize = "III")]
+        Three,
+        /// II
+        #[strum(serialize = "II")]
+        Two,
+        /// I
+        #[strum(serialize = "I")]
+        One,
+    }
 }
@@ -1,11 +1,87 @@
 //! Contains any miscellaneous constants
 
+/// Declares an enum of string values of the Riot Api that keeps values unknown to this crate in an `Unknown` variant,
+/// so new values do not fail deserialization.
+///
+/// The variants are parsed and displayed through their `strum` serializations, while `Unknown` keeps the value as
+/// received in both directions.
+macro_rules! fallback_enum {
+    (
+        $(#[$attribute:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_attribute:meta])*
+                $variant:ident,
+            )*
+        }
+    ) => {
+        $(#[$attribute])*
+        #[derive(
+            Clone,
+            Debug,
+            serde::Deserialize,
+            strum_macros::EnumString,
+            Eq,
+            Hash,
+            strum_macros::IntoStaticStr,
+            PartialEq,
+            serde::Serialize,
+        )]
+        #[serde(from = "String", into = "String")]
+        pub enum $name {
+            $(
+                $(#[$variant_attribute])*
+                $variant,
+            )*
+            /// A value this version of the crate does not know about, kept as received.
+            #[strum(default)]
+            Unknown(String),
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                value
+                    .parse()
+                    .expect("values that match no variant parse as Unknown")
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                match value {
+                    $name::Unknown(value) => value,
+                    known => <&str>::from(known).to_string(),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                match self {
+                    $name::Unknown(value) => f.pad(value),
+                    known => f.pad(known.into()),
+                }
+            }
+        }
+    };
+}
+
+mod challenge_level;
 mod division;
+mod position;
 mod queue;
+mod queue_id;
 mod region;
+mod regional_route;
+mod tft_queue;
 mod tier;
 
+pub use challenge_level::ChallengeLevel;
 pub use division::Division;
+pub use position::Position;
 pub use queue::Queue;
+pub use queue_id::QueueId;
 pub use region::Region;
+pub use regional_route::RegionalRoute;
+pub use tft_queue::TftQueue;
 pub use tier::Tier;
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// The position a participant played on summoner's rift, in pick order.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Display,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Position {
+    #[strum(serialize = "TOP")]
+    Top,
+    #[strum(serialize = "JUNGLE")]
+    Jungle,
+    #[strum(serialize = "MIDDLE")]
+    Middle,
+    #[strum(serialize = "BOTTOM")]
+    Bottom,
+    /// UTILITY, also known as support.
+    #[strum(serialize = "UTILITY")]
+    Utility,
+}
@@ -1,15 +1,29 @@
-use strum_macros::Display;
-
-/// League of legends queues.
-#[derive(Clone, Display)]
-pub enum Queue {
-    /// RANKED_SOLO_5x5
-    #[strum(serialize = "RANKED_SOLO_5x5")]
-    RankedSoloQueue,
-    /// RANKED_FLEX_SR
-    #[strum(serialize = "RANKED_FLEX_SR")]
-    RankedFlexQueue,
-    /// RANKED_FLEX_TT
-    #[strum(serialize = "RANKED_FLEX_TT")]
-    RankedFlexTwistedTreeline,
+fallback_enum! {
+    /// League of legends queues.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::enums::Queue;
+    /// let queue = "RANKED_SOLO_5x5".parse::<Queue>();
+    ///
+    /// assert_eq!(queue, Ok(Queue::RankedSoloQueue));
+    /// assert_eq!(Queue::RankedSoloQueue.to_string(), "RANKED_SOLO_5x5");
+    ///
+    /// // Queues added after this version of the crate are kept as received.
+    /// let queue = Queue::from("CHERRY".to_string());
+    ///
+    /// assert_eq!(queue, Queue::Unknown("CHERRY".to_string()));
+    /// assert_eq!(queue.to_string(), "CHERRY");
+    /// ```
+    pub enum Queue {
+        /// RANKED_SOLO_5x5
+        #[strum(serialize = "RANKED_SOLO_5x5")]
+        RankedSoloQueue,
+        /// RANKED_FLEX_SR
+        #[strum(serialize = "RANKED_FLEX_SR")]
+        RankedFlexQueue,
+        /// RANKED_FLEX_TT
+        #[strum(serialize = "RANKED_FLEX_TT")]
+        RankedFlexTwistedTreeline,
+    }
 }
@@ -0,0 +1,531 @@
+use super::Queue;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter, Result},
+    hash::{Hash, Hasher},
+    mem::discriminant,
+};
+
+/// League of legends queue ids as found in the match types.
+///
+/// Queue ids that are not known to this crate are kept as `QueueId::Unknown`. Queue ids compare and hash by their
+/// numeric id, so `QueueId::Unknown(420)` equals `QueueId::RankedSolo`.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::enums::QueueId;
+/// let queue_id = QueueId::from(420);
+///
+/// assert_eq!(queue_id, QueueId::RankedSolo);
+/// assert_eq!(queue_id.map(), Some("Summoner's Rift"));
+/// assert!(queue_id.is_ranked());
+/// assert_eq!(QueueId::from(12345), QueueId::Unknown(12345));
+/// assert_eq!(QueueId::Unknown(420), QueueId::RankedSolo);
+/// assert!(QueueId::Unknown(420).is_ranked());
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum QueueId {
+    /// 0, custom games
+    Custom,
+    /// 72, 1v1 Snowdown Showdown games
+    SnowdownShowdown1v1,
+    /// 73, 2v2 Snowdown Showdown games
+    SnowdownShowdown2v2,
+    /// 75, 6v6 Hexakill games
+    Hexakill,
+    /// 76, Ultra Rapid Fire games
+    UltraRapidFire,
+    /// 78, One For All: Mirror Mode games
+    OneForAllMirrorMode,
+    /// 83, Co-op vs AI Ultra Rapid Fire games
+    CoopVsAiUltraRapidFire,
+    /// 98, 6v6 Hexakill games
+    TwistedTreelineHexakill,
+    /// 100, 5v5 ARAM games
+    ButchersBridgeAram,
+    /// 310, Nemesis games
+    Nemesis,
+    /// 313, Black Market Brawlers games
+    BlackMarketBrawlers,
+    /// 317, Definitely Not Dominion games
+    DefinitelyNotDominion,
+    /// 325, All Random games
+    AllRandom,
+    /// 400, 5v5 Draft Pick games
+    NormalDraftPick,
+    /// 420, 5v5 Ranked Solo games
+    RankedSolo,
+    /// 430, 5v5 Blind Pick games
+    NormalBlindPick,
+    /// 440, 5v5 Ranked Flex games
+    RankedFlex,
+    /// 450, 5v5 ARAM games
+    Aram,
+    /// 460, 3v3 Blind Pick games
+    TwistedTreelineBlindPick,
+    /// 470, 3v3 Ranked Flex games
+    TwistedTreelineRankedFlex,
+    /// 600, Blood Hunt Assassin games
+    BloodHuntAssassin,
+    /// 610, Dark Star: Singularity games
+    DarkStarSingularity,
+    /// 700, Clash games
+    Clash,
+    /// 800, Co-op vs. AI Intermediate Bot games
+    TwistedTreelineCoopVsAiIntermediate,
+    /// 810, Co-op vs. AI Intro Bot games
+    TwistedTreelineCoopVsAiIntro,
+    /// 820, Co-op vs. AI Beginner Bot games
+    TwistedTreelineCoopVsAiBeginner,
+    /// 830, Co-op vs. AI Intro Bot games
+    CoopVsAiIntro,
+    /// 840, Co-op vs. AI Beginner Bot games
+    CoopVsAiBeginner,
+    /// 850, Co-op vs. AI Intermediate Bot games
+    CoopVsAiIntermediate,
+    /// 900, ARURF games
+    AllRandomUltraRapidFire,
+    /// 910, Ascension games
+    Ascension,
+    /// 920, Legend of the Poro King games
+    LegendOfThePoroKing,
+    /// 940, Nexus Siege games
+    NexusSiege,
+    /// 950, Doom Bots Voting games
+    DoomBotsVoting,
+    /// 960, Doom Bots Standard games
+    DoomBotsStandard,
+    /// 980, Star Guardian Invasion: Normal games
+    StarGuardianInvasionNormal,
+    /// 990, Star Guardian Invasion: Onslaught games
+    StarGuardianInvasionOnslaught,
+    /// 1000, PROJECT: Hunters games
+    ProjectHunters,
+    /// 1010, Snow ARURF games
+    SnowAllRandomUltraRapidFire,
+    /// 1020, One for All games
+    OneForAll,
+    /// 1030, Odyssey Extraction: Intro games
+    OdysseyExtractionIntro,
+    /// 1040, Odyssey Extraction: Cadet games
+    OdysseyExtractionCadet,
+    /// 1050, Odyssey Extraction: Crewmember games
+    OdysseyExtractionCrewmember,
+    /// 1060, Odyssey Extraction: Captain games
+    OdysseyExtractionCaptain,
+    /// 1070, Odyssey Extraction: Onslaught games
+    OdysseyExtractionOnslaught,
+    /// 1090, Teamfight Tactics games
+    TeamfightTactics,
+    /// 1100, Ranked Teamfight Tactics games
+    RankedTeamfightTactics,
+    /// 1110, Teamfight Tactics Tutorial games
+    TeamfightTacticsTutorial,
+    /// 1111, Teamfight Tactics test games
+    TeamfightTacticsTest,
+    /// 1200, Nexus Blitz games
+    DeprecatedNexusBlitz,
+    /// 1300, Nexus Blitz games
+    NexusBlitz,
+    /// 2000, Tutorial 1
+    Tutorial1,
+    /// 2010, Tutorial 2
+    Tutorial2,
+    /// 2020, Tutorial 3
+    Tutorial3,
+    /// A queue id that is not known to this crate.
+    Unknown(i32),
+}
+
+/// Queue id, map name and description for each known queue.
+const QUEUES: [(QueueId, i32, &str, Option<&str>); 54] = [
+    (QueueId::Custom, 0, "Custom games", None),
+    (
+        QueueId::SnowdownShowdown1v1,
+        72,
+        "Howling Abyss",
+        Some("1v1 Snowdown Showdown games"),
+    ),
+    (
+        QueueId::SnowdownShowdown2v2,
+        73,
+        "Howling Abyss",
+        Some("2v2 Snowdown Showdown games"),
+    ),
+    (
+        QueueId::Hexakill,
+        75,
+        "Summoner's Rift",
+        Some("6v6 Hexakill games"),
+    ),
+    (
+        QueueId::UltraRapidFire,
+        76,
+        "Summoner's Rift",
+        Some("Ultra Rapid Fire games"),
+    ),
+    (
+        QueueId::OneForAllMirrorMode,
+        78,
+        "Howling Abyss",
+        Some("One For All: Mirror Mode games"),
+    ),
+    (
+        QueueId::CoopVsAiUltraRapidFire,
+        83,
+        "Summoner's Rift",
+        Some("Co-op vs AI Ultra Rapid Fire games"),
+    ),
+    (
+        QueueId::TwistedTreelineHexakill,
+        98,
+        "Twisted Treeline",
+        Some("6v6 Hexakill games"),
+    ),
+    (
+        QueueId::ButchersBridgeAram,
+        100,
+        "Butcher's Bridge",
+        Some("5v5 ARAM games"),
+    ),
+    (
+        QueueId::Nemesis,
+        310,
+        "Summoner's Rift",
+        Some("Nemesis games"),
+    ),
+    (
+        QueueId::BlackMarketBrawlers,
+        313,
+        "Summoner's Rift",
+        Some("Black Market Brawlers games"),
+    ),
+    (
+        QueueId::DefinitelyNotDominion,
+        317,
+        "Crystal Scar",
+        Some("Definitely Not Dominion games"),
+    ),
+    (
+        QueueId::AllRandom,
+        325,
+        "Summoner's Rift",
+        Some("All Random games"),
+    ),
+    (
+        QueueId::NormalDraftPick,
+        400,
+        "Summoner's Rift",
+        Some("5v5 Draft Pick games"),
+    ),
+    (
+        QueueId::RankedSolo,
+        420,
+        "Summoner's Rift",
+        Some("5v5 Ranked Solo games"),
+    ),
+    (
+        QueueId::NormalBlindPick,
+        430,
+        "Summoner's Rift",
+        Some("5v5 Blind Pick games"),
+    ),
+    (
+        QueueId::RankedFlex,
+        440,
+        "Summoner's Rift",
+        Some("5v5 Ranked Flex games"),
+    ),
+    (QueueId::Aram, 450, "Howling Abyss", Some("5v5 ARAM games")),
+    (
+        QueueId::TwistedTreelineBlindPick,
+        460,
+        "Twisted Treeline",
+        Some("3v3 Blind Pick games"),
+    ),
+    (
+        QueueId::TwistedTreelineRankedFlex,
+        470,
+        "Twisted Treeline",
+        Some("3v3 Ranked Flex games"),
+    ),
+    (
+        QueueId::BloodHuntAssassin,
+        600,
+        "Summoner's Rift",
+        Some("Blood Hunt Assassin games"),
+    ),
+    (
+        QueueId::DarkStarSingularity,
+        610,
+        "Cosmic Ruins",
+        Some("Dark Star: Singularity games"),
+    ),
+    (QueueId::Clash, 700, "Summoner's Rift", Some("Clash games")),
+    (
+        QueueId::TwistedTreelineCoopVsAiIntermediate,
+        800,
+        "Twisted Treeline",
+        Some("Co-op vs. AI Intermediate Bot games"),
+    ),
+    (
+        QueueId::TwistedTreelineCoopVsAiIntro,
+        810,
+        "Twisted Treeline",
+        Some("Co-op vs. AI Intro Bot games"),
+    ),
+    (
+        QueueId::TwistedTreelineCoopVsAiBeginner,
+        820,
+        "Twisted Treeline",
+        Some("Co-op vs. AI Beginner Bot games"),
+    ),
+    (
+        QueueId::CoopVsAiIntro,
+        830,
+        "Summoner's Rift",
+        Some("Co-op vs. AI Intro Bot games"),
+    ),
+    (
+        QueueId::CoopVsAiBeginner,
+        840,
+        "Summoner's Rift",
+        Some("Co-op vs. AI Beginner Bot games"),
+    ),
+    (
+        QueueId::CoopVsAiIntermediate,
+        850,
+        "Summoner's Rift",
+        Some("Co-op vs. AI Intermediate Bot games"),
+    ),
+    (
+        QueueId::AllRandomUltraRapidFire,
+        900,
+        "Summoner's Rift",
+        Some("ARURF games"),
+    ),
+    (
+        QueueId::Ascension,
+        910,
+        "Crystal Scar",
+        Some("Ascension games"),
+    ),
+    (
+        QueueId::LegendOfThePoroKing,
+        920,
+        "Howling Abyss",
+        Some("Legend of the Poro King games"),
+    ),
+    (
+        QueueId::NexusSiege,
+        940,
+        "Summoner's Rift",
+        Some("Nexus Siege games"),
+    ),
+    (
+        QueueId::DoomBotsVoting,
+        950,
+        "Summoner's Rift",
+        Some("Doom Bots Voting games"),
+    ),
+    (
+        QueueId::DoomBotsStandard,
+        960,
+        "Summoner's Rift",
+        Some("Doom Bots Standard games"),
+    ),
+    (
+        QueueId::StarGuardianInvasionNormal,
+        980,
+        "Valoran City Park",
+        Some("Star Guardian Invasion: Normal games"),
+    ),
+    (
+        QueueId::StarGuardianInvasionOnslaught,
+        990,
+        "Valoran City Park",
+        Some("Star Guardian Invasion: Onslaught games"),
+    ),
+    (
+        QueueId::ProjectHunters,
+        1000,
+        "Overcharge",
+        Some("PROJECT: Hunters games"),
+    ),
+    (
+        QueueId::SnowAllRandomUltraRapidFire,
+        1010,
+        "Summoner's Rift",
+        Some("Snow ARURF games"),
+    ),
+    (
+        QueueId::OneForAll,
+        1020,
+        "Summoner's Rift",
+        Some("One for All games"),
+    ),
+    (
+        QueueId::OdysseyExtractionIntro,
+        1030,
+        "Crash Site",
+        Some("Odyssey Extraction: Intro games"),
+    ),
+    (
+        QueueId::OdysseyExtractionCadet,
+        1040,
+        "Crash Site",
+        Some("Odyssey Extraction: Cadet games"),
+    ),
+    (
+        QueueId::OdysseyExtractionCrewmember,
+        1050,
+        "Crash Site",
+        Some("Odyssey Extraction: Crewmember games"),
+    ),
+    (
+        QueueId::OdysseyExtractionCaptain,
+        1060,
+        "Crash Site",
+        Some("Odyssey Extraction: Captain games"),
+    ),
+    (
+        QueueId::OdysseyExtractionOnslaught,
+        1070,
+        "Crash Site",
+        Some("Odyssey Extraction: Onslaught games"),
+    ),
+    (
+        QueueId::TeamfightTactics,
+        1090,
+        "Convergence",
+        Some("Teamfight Tactics games"),
+    ),
+    (
+        QueueId::RankedTeamfightTactics,
+        1100,
+        "Convergence",
+        Some("Ranked Teamfight Tactics games"),
+    ),
+    (
+        QueueId::TeamfightTacticsTutorial,
+        1110,
+        "Convergence",
+        Some("Teamfight Tactics Tutorial games"),
+    ),
+    (
+        QueueId::TeamfightTacticsTest,
+        1111,
+        "Convergence",
+        Some("Teamfight Tactics test games"),
+    ),
+    (
+        QueueId::DeprecatedNexusBlitz,
+        1200,
+        "Nexus Blitz",
+        Some("Nexus Blitz games"),
+    ),
+    (
+        QueueId::NexusBlitz,
+        1300,
+        "Nexus Blitz",
+        Some("Nexus Blitz games"),
+    ),
+    (
+        QueueId::Tutorial1,
+        2000,
+        "Summoner's Rift",
+        Some("Tutorial 1"),
+    ),
+    (
+        QueueId::Tutorial2,
+        2010,
+        "Summoner's Rift",
+        Some("Tutorial 2"),
+    ),
+    (
+        QueueId::Tutorial3,
+        2020,
+        "Summoner's Rift",
+        Some("Tutorial 3"),
+    ),
+];
+
+impl QueueId {
+    fn metadata(&self) -> Option<&(QueueId, i32, &'static str, Option<&'static str>)> {
+        match self {
+            QueueId::Unknown(id) => QUEUES.iter().find(|(_, queue_id, ..)| queue_id == id),
+            known => QUEUES
+                .iter()
+                .find(|(queue_id, ..)| discriminant(queue_id) == discriminant(known)),
+        }
+    }
+
+    /// The numeric queue id.
+    pub fn id(&self) -> i32 {
+        match self {
+            QueueId::Unknown(id) => *id,
+            known => known.metadata().map_or(0, |(_, id, ..)| *id),
+        }
+    }
+
+    /// The name of the map this queue is played on.
+    pub fn map(&self) -> Option<&'static str> {
+        self.metadata().map(|(_, _, map, _)| *map)
+    }
+
+    /// The description of this queue, e.g. `5v5 Ranked Solo games`.
+    pub fn description(&self) -> Option<&'static str> {
+        self.metadata().and_then(|(.., description)| *description)
+    }
+
+    /// Whether games in this queue affect ranked standings.
+    pub fn is_ranked(&self) -> bool {
+        self.ranked_queue().is_some() || *self == QueueId::RankedTeamfightTactics
+    }
+
+    /// The `Queue` used by the league endpoints for this queue, if it is a ranked league of legends queue.
+    pub fn ranked_queue(&self) -> Option<Queue> {
+        match QueueId::from(self.id()) {
+            QueueId::RankedSolo => Some(Queue::RankedSoloQueue),
+            QueueId::RankedFlex => Some(Queue::RankedFlexQueue),
+            QueueId::TwistedTreelineRankedFlex => Some(Queue::RankedFlexTwistedTreeline),
+            _ => None,
+        }
+    }
+}
+
+impl From<i32> for QueueId {
+    fn from(id: i32) -> Self {
+        QUEUES
+            .iter()
+            .find(|(_, queue_id, ..)| *queue_id == id)
+            .map_or(QueueId::Unknown(id), |(queue_id, ..)| *queue_id)
+    }
+}
+
+impl From<QueueId> for i32 {
+    fn from(queue_id: QueueId) -> Self {
+        queue_id.id()
+    }
+}
+
+impl PartialEq for QueueId {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for QueueId {}
+
+impl Hash for QueueId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
+}
+
+impl Display for QueueId {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.id())
+    }
+}
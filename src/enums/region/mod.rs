@@ -1,7 +1,25 @@
+use super::RegionalRoute;
+use serde::{Deserialize, Serialize};
+use std::{convert::TryFrom, str::FromStr};
+use strum::ParseError;
 use strum_macros::{Display, EnumIter};
 
 /// All regions available for the riot API.
-#[derive(Clone, Display, EnumIter)]
+///
+/// `Display` gives the host used for requests, while parsing and serde use the platform id found in the match
+/// types, e.g. `NA1`.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::enums::Region;
+/// let region = "EUW1".parse::<Region>();
+///
+/// assert_eq!(region, Ok(Region::EuropeWest));
+/// assert_eq!(Region::EuropeWest.platform_id(), "EUW1");
+/// assert_eq!(Region::EuropeWest.to_string(), "euw1.api.riotgames.com");
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Display, EnumIter, Eq, Hash, PartialEq, Serialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum Region {
     #[strum(serialize = "br1.api.riotgames.com")]
     Brazil,
@@ -25,4 +43,112 @@ pub enum Region {
     Turkey,
     #[strum(serialize = "ru.api.riotgames.com")]
     Russia,
+    #[strum(serialize = "ph2.api.riotgames.com")]
+    Philippines,
+    #[strum(serialize = "sg2.api.riotgames.com")]
+    Singapore,
+    #[strum(serialize = "th2.api.riotgames.com")]
+    Thailand,
+    #[strum(serialize = "tw2.api.riotgames.com")]
+    Taiwan,
+    #[strum(serialize = "vn2.api.riotgames.com")]
+    Vietnam,
+    #[strum(serialize = "pbe1.api.riotgames.com")]
+    PublicBetaEnvironment,
+}
+
+impl Region {
+    /// The platform id of this region, e.g. `NA1`.
+    pub fn platform_id(&self) -> &'static str {
+        match self {
+            Region::Brazil => "BR1",
+            Region::EuropeNordicAndEast => "EUN1",
+            Region::EuropeWest => "EUW1",
+            Region::Japan => "JP1",
+            Region::Korea => "KR",
+            Region::LatinAmericaNorth => "LA1",
+            Region::LatinAmericaSouth => "LA2",
+            Region::NorthAmerica => "NA1",
+            Region::Oceania => "OC1",
+            Region::Turkey => "TR1",
+            Region::Russia => "RU",
+            Region::Philippines => "PH2",
+            Region::Singapore => "SG2",
+            Region::Thailand => "TH2",
+            Region::Taiwan => "TW2",
+            Region::Vietnam => "VN2",
+            Region::PublicBetaEnvironment => "PBE1",
+        }
+    }
+
+    /// The `RegionalRoute` that serves this region for regionally routed APIs.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::enums::{Region, RegionalRoute};
+    /// let route = Region::EuropeWest.regional_route();
+    ///
+    /// assert_eq!(route.to_string(), RegionalRoute::Europe.to_string());
+    /// ```
+    pub fn regional_route(&self) -> RegionalRoute {
+        match self {
+            Region::Brazil
+            | Region::LatinAmericaNorth
+            | Region::LatinAmericaSouth
+            | Region::NorthAmerica
+            | Region::PublicBetaEnvironment => RegionalRoute::Americas,
+            Region::Japan | Region::Korea => RegionalRoute::Asia,
+            Region::EuropeNordicAndEast | Region::EuropeWest | Region::Turkey | Region::Russia => {
+                RegionalRoute::Europe
+            }
+            Region::Oceania
+            | Region::Philippines
+            | Region::Singapore
+            | Region::Thailand
+            | Region::Taiwan
+            | Region::Vietnam => RegionalRoute::SouthEastAsia,
+        }
+    }
+}
+
+impl FromStr for Region {
+    type Err = ParseError;
+
+    /// Parses a platform id, ignoring case. The legacy platform ids without a number, e.g. `NA`, are accepted.
+    fn from_str(platform_id: &str) -> Result<Self, Self::Err> {
+        match platform_id.to_uppercase().as_str() {
+            "BR1" | "BR" => Ok(Region::Brazil),
+            "EUN1" | "EUN" | "EUNE" => Ok(Region::EuropeNordicAndEast),
+            "EUW1" | "EUW" => Ok(Region::EuropeWest),
+            "JP1" | "JP" => Ok(Region::Japan),
+            "KR" => Ok(Region::Korea),
+            "LA1" | "LAN" => Ok(Region::LatinAmericaNorth),
+            "LA2" | "LAS" => Ok(Region::LatinAmericaSouth),
+            "NA1" | "NA" => Ok(Region::NorthAmerica),
+            "OC1" | "OCE" => Ok(Region::Oceania),
+            "TR1" | "TR" => Ok(Region::Turkey),
+            "RU" => Ok(Region::Russia),
+            "PH2" | "PH" => Ok(Region::Philippines),
+            "SG2" | "SG" => Ok(Region::Singapore),
+            "TH2" | "TH" => Ok(Region::Thailand),
+            "TW2" | "TW" => Ok(Region::Taiwan),
+            "VN2" | "VN" => Ok(Region::Vietnam),
+            "PBE1" | "PBE" => Ok(Region::PublicBetaEnvironment),
+            _ => Err(ParseError::VariantNotFound),
+        }
+    }
+}
+
+impl TryFrom<String> for Region {
+    type Error = ParseError;
+
+    fn try_from(platform_id: String) -> Result<Self, Self::Error> {
+        platform_id.parse()
+    }
+}
+
+impl From<Region> for String {
+    fn from(region: Region) -> Self {
+        region.platform_id().to_string()
+    }
 }
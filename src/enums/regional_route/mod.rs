@@ -0,0 +1,14 @@
+use strum_macros::{Display, EnumIter};
+
+/// Regional routing values used by APIs that are not served per platform.
+#[derive(Clone, Display, EnumIter)]
+pub enum RegionalRoute {
+    #[strum(serialize = "americas.api.riotgames.com")]
+    Americas,
+    #[strum(serialize = "asia.api.riotgames.com")]
+    Asia,
+    #[strum(serialize = "europe.api.riotgames.com")]
+    Europe,
+    #[strum(serialize = "sea.api.riotgames.com")]
+    SouthEastAsia,
+}
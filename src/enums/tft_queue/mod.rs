@@ -0,0 +1,15 @@
+use strum_macros::Display;
+
+/// Teamfight tactics queues.
+#[derive(Clone, Display)]
+pub enum TftQueue {
+    /// RANKED_TFT
+    #[strum(serialize = "RANKED_TFT")]
+    RankedTft,
+    /// RANKED_TFT_TURBO
+    #[strum(serialize = "RANKED_TFT_TURBO")]
+    RankedTftTurbo,
+    /// RANKED_TFT_DOUBLE_UP
+    #[strum(serialize = "RANKED_TFT_DOUBLE_UP")]
+    RankedTftDoubleUp,
+}
@@ -1,18 +1,43 @@
-use strum_macros::Display;
+fallback_enum! {
+    /// Ranked tiers in league of legends, ordered from lowest to highest.
+    ///
+    /// Tiers that are not known to this crate are kept as `Tier::Unknown` and ordered after the known tiers.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::enums::Tier;
+    /// let tier = "GRANDMASTER".parse::<Tier>();
+    ///
+    /// assert_eq!(tier, Ok(Tier::Grandmaster));
+    /// assert_eq!(Tier::Grandmaster.to_string(), "GRANDMASTER");
+    /// assert_eq!(Tier::from("EMERALD".to_string()), Tier::Unknown("EMERALD".to_string()));
+    /// ```
+    #[derive(Ord, PartialOrd)]
+    pub enum Tier {
+        #[strum(serialize = "IRON")]
+        Iron,
+        #[strum(serialize = "BRONZE")]
+        Bronze,
+        #[strum(serialize = "SILVER")]
+        Silver,
+        #[strum(serialize = "GOLD")]
+        Gold,
+        #[strum(serialize = "PLATINUM")]
+        Platinum,
+        #[strum(serialize = "DIAMOND")]
+        Diamond,
+        #[strum(serialize = "MASTER")]
+        Master,
+        #[strum(serialize = "GRANDMASTER")]
+        Grandmaster,
+        #[strum(serialize = "CHALLENGER")]
+        Challenger,
+    }
+}
 
-/// Ranked tiers in league of legends.
-#[derive(Clone, Display)]
-pub enum Tier {
-    #[strum(serialize = "IRON")]
-    Iron,
-    #[strum(serialize = "BRONZE")]
-    Bronze,
-    #[strum(serialize = "SILVER")]
-    Silver,
-    #[strum(serialize = "GOLD")]
-    Gold,
-    #[strum(serialize = "PLATINUM")]
-    Platinum,
-    #[strum(serialize = "DIAMOND")]
-    Diamond,
+impl Tier {
+    /// Whether this is an apex tier (master, grandmaster or challenger), which have no divisions.
+    pub fn is_apex(&self) -> bool {
+        matches!(self, Tier::Master | Tier::Grandmaster | Tier::Challenger)
+    }
 }
@@ -1,10 +1,19 @@
+use crate::enums::Tier;
 use reqwest::Error as ReqwestError;
+#[cfg(feature = "snapshot")]
+use serde_json::Error as JsonError;
+#[cfg(feature = "snapshot")]
+use std::io::Error as IoError;
 use std::time::Duration;
 use thiserror::Error;
 use url::ParseError;
 
 /// Possible error types from this crate.
+///
+/// Variants may be added by features, such as the io and json errors of the `snapshot` feature, so matches need a
+/// wildcard arm.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     #[error("Bad Request")]
     BadRequest,
@@ -26,6 +35,14 @@ pub enum Error {
     RequestError(#[from] ReqwestError),
     #[error("Parse Error: {0}")]
     ParseError(#[from] ParseError),
+    #[error("Not An Apex Tier: {0}")]
+    NotApexTier(Tier),
+    #[cfg(feature = "snapshot")]
+    #[error("Io Error: {0}")]
+    IoError(#[from] IoError),
+    #[cfg(feature = "snapshot")]
+    #[error("Json Error: {0}")]
+    JsonError(#[from] JsonError),
     #[error("Unknown")]
     Unknown,
 }
@@ -0,0 +1,246 @@
+use super::LadderSnapshot;
+use crate::{
+    enums::{Division, Queue, Region, Tier},
+    types::{LeagueEntry, LeagueList, SummonerId},
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::SystemTime};
+use strum::IntoEnumIterator;
+
+/// Tiers below master in descending order, which are paged by division.
+const DIVISION_TIERS: [Tier; 6] = [
+    Tier::Diamond,
+    Tier::Platinum,
+    Tier::Gold,
+    Tier::Silver,
+    Tier::Bronze,
+    Tier::Iron,
+];
+/// Divisions in descending order.
+const DIVISIONS: [Division; 4] = [
+    Division::One,
+    Division::Two,
+    Division::Three,
+    Division::Four,
+];
+
+/// A single request of a `LadderCrawl`.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum LadderPage {
+    /// The whole master, grandmaster or challenger league.
+    Apex(Tier),
+    /// A page of the entries of a tier and division below master, starting at page 1.
+    Entries {
+        tier: Tier,
+        division: Division,
+        page: i32,
+    },
+}
+
+/// The progress of crawling the ladder of a queue in a region, see `client.ladder().crawl(...)`.
+///
+/// The crawl keeps track of the pages that are left and the entries collected so far. It can be serialized when a
+/// crawl is interrupted and resumed later by passing it to `crawl(...)` again.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::{Queue, Region},
+/// #    ladder::LadderCrawl,
+/// # };
+/// let crawl = LadderCrawl::new(Region::NorthAmerica, Queue::RankedSoloQueue).with_max_concurrency(8);
+///
+/// // Challenger, grandmaster and master, followed by the first page of every division below.
+/// assert_eq!(crawl.pending_pages().len(), 3 + 6 * 4);
+/// assert!(!crawl.is_finished());
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LadderCrawl {
+    pub region: Region,
+    pub queue: Queue,
+    /// The maximum number of pages requested at the same time, lowered temporarily while rate limited.
+    pub max_concurrency: usize,
+    /// The maximum number of pages requested per minute, paced evenly over the crawl.
+    pub max_requests_per_minute: u32,
+    started_at: SystemTime,
+    pending: Vec<LadderPage>,
+    entries: HashMap<SummonerId, LeagueEntry>,
+}
+
+impl LadderCrawl {
+    /// Constructs a crawl of the whole ladder of `queue` in `region`.
+    pub fn new(region: Region, queue: Queue) -> Self {
+        let mut pending = vec![
+            LadderPage::Apex(Tier::Challenger),
+            LadderPage::Apex(Tier::Grandmaster),
+            LadderPage::Apex(Tier::Master),
+        ];
+
+        for tier in DIVISION_TIERS.iter() {
+            for division in DIVISIONS.iter() {
+                pending.push(LadderPage::Entries {
+                    tier: tier.clone(),
+                    division: division.clone(),
+                    page: 1,
+                });
+            }
+        }
+
+        LadderCrawl {
+            region,
+            queue,
+            max_concurrency: 4,
+            max_requests_per_minute: 50,
+            started_at: SystemTime::now(),
+            pending,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Constructs a crawl of the ladder of `queue` for every region except the public beta environment.
+    pub fn for_all_regions(queue: Queue) -> Vec<Self> {
+        Region::iter()
+            .filter(|region| region != &Region::PublicBetaEnvironment)
+            .map(|region| LadderCrawl::new(region, queue.clone()))
+            .collect()
+    }
+
+    /// Sets the maximum number of pages requested at the same time, at least 1.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Sets the maximum number of pages requested per minute, at least 1.
+    ///
+    /// The default of 50 stays within the limits of a development API key.
+    pub fn with_max_requests_per_minute(mut self, max_requests_per_minute: u32) -> Self {
+        self.max_requests_per_minute = max_requests_per_minute.max(1);
+        self
+    }
+
+    /// The pages that are left to crawl.
+    pub fn pending_pages(&self) -> &[LadderPage] {
+        &self.pending
+    }
+
+    /// Whether every page has been crawled.
+    pub fn is_finished(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// The number of distinct players collected so far.
+    pub fn entries_crawled(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// A snapshot of the entries collected so far, which covers the whole ladder once the crawl is finished.
+    pub fn snapshot(&self) -> LadderSnapshot {
+        LadderSnapshot::new(
+            self.region,
+            self.queue.clone(),
+            self.started_at,
+            self.entries.values().cloned().collect(),
+        )
+    }
+
+    /// Records the entries of a crawled page and queues the page after it, unless the page was empty.
+    ///
+    /// Players that moved between pages during the crawl keep the entry seen last. This is called by
+    /// `client.ladder().crawl(...)`, call it directly only when requesting the pages yourself.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::{Division, Queue, Region, Tier},
+    /// #    ladder::{LadderCrawl, LadderPage},
+    /// #    types::LeagueEntry,
+    /// # };
+    /// # let entry: LeagueEntry = serde_json::from_value(serde_json::json!({
+    /// #     "leagueId": "LEAGUE_ID", "summonerId": "SUMMONER_ID", "summonerName": "SUMMONER_NAME",
+    /// #     "queueType": "RANKED_SOLO_5x5", "tier": "DIAMOND", "rank": "I", "leaguePoints": 50,
+    /// #     "wins": 100, "losses": 90, "hotStreak": false, "veteran": false, "freshBlood": false,
+    /// #     "inactive": false
+    /// # })).unwrap();
+    /// let mut crawl = LadderCrawl::new(Region::NorthAmerica, Queue::RankedSoloQueue);
+    /// let page = |page| LadderPage::Entries { tier: Tier::Diamond, division: Division::One, page };
+    ///
+    /// // A page with entries queues the page after it.
+    /// crawl.complete(&page(1), vec![entry]);
+    ///
+    /// assert!(!crawl.pending_pages().contains(&page(1)));
+    /// assert!(crawl.pending_pages().contains(&page(2)));
+    /// assert_eq!(crawl.entries_crawled(), 1);
+    ///
+    /// // An interrupted crawl resumes where it stopped.
+    /// let saved = serde_json::to_string(&crawl).unwrap();
+    /// let mut crawl: LadderCrawl = serde_json::from_str(&saved).unwrap();
+    ///
+    /// assert!(crawl.pending_pages().contains(&page(2)));
+    /// assert_eq!(crawl.entries_crawled(), 1);
+    ///
+    /// // An empty page ends the division.
+    /// crawl.complete(&page(2), Vec::new());
+    ///
+    /// assert!(!crawl.pending_pages().contains(&page(2)));
+    /// assert!(!crawl.pending_pages().contains(&page(3)));
+    /// assert_eq!(crawl.pending_pages().len(), 3 + 6 * 4 - 1);
+    /// ```
+    pub fn complete(&mut self, page: &LadderPage, entries: Vec<LeagueEntry>) {
+        if let Some(index) = self.pending.iter().position(|pending| pending == page) {
+            self.pending.remove(index);
+        }
+
+        if let LadderPage::Entries {
+            tier,
+            division,
+            page,
+        } = page
+        {
+            if !entries.is_empty() {
+                self.pending.push(LadderPage::Entries {
+                    tier: tier.clone(),
+                    division: division.clone(),
+                    page: page + 1,
+                });
+            }
+        }
+
+        for entry in entries {
+            self.entries.insert(entry.summoner_id.clone(), entry);
+        }
+    }
+}
+
+/// Converts the items of an apex league into entries, which carry their tier, queue and league id.
+pub(crate) fn league_list_entries(league_list: LeagueList) -> Vec<LeagueEntry> {
+    let LeagueList {
+        league_id,
+        entries,
+        tier,
+        queue,
+        ..
+    } = league_list;
+
+    entries
+        .into_iter()
+        .map(|item| LeagueEntry {
+            league_id: league_id.clone(),
+            summoner_id: item.summoner_id,
+            summoner_name: item.summoner_name,
+            queue_type: queue.clone(),
+            tier: tier.clone(),
+            rank: item.rank,
+            league_points: item.league_points,
+            wins: item.wins,
+            losses: item.losses,
+            hot_streak: item.hot_streak,
+            veteran: item.veteran,
+            fresh_blood: item.fresh_blood,
+            inactive: item.inactive,
+            mini_series: item.mini_series,
+            #[cfg(feature = "extra_fields")]
+            extra: item.extra,
+        })
+        .collect()
+}
@@ -0,0 +1,217 @@
+use super::LadderSnapshot;
+use crate::{rank::Rank, types::LeagueEntry};
+
+/// The changes between two snapshots of the same ladder.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::{Queue, Region},
+/// #    ladder::LadderSnapshot,
+/// #    types::{LeagueEntry, SummonerId},
+/// # };
+/// # use std::time::SystemTime;
+/// # let entry = |summoner_id: &str, tier: &str, rank: &str, league_points: i32| -> LeagueEntry {
+/// #     serde_json::from_value(serde_json::json!({
+/// #         "leagueId": "LEAGUE_ID", "summonerId": summoner_id, "summonerName": summoner_id,
+/// #         "queueType": "RANKED_SOLO_5x5", "tier": tier, "rank": rank, "leaguePoints": league_points,
+/// #         "wins": 100, "losses": 90, "hotStreak": false, "veteran": false, "freshBlood": false,
+/// #         "inactive": false
+/// #     })).unwrap()
+/// # };
+/// # let snapshot = |entries| {
+/// #     LadderSnapshot::new(Region::NorthAmerica, Queue::RankedSoloQueue, SystemTime::now(), entries)
+/// # };
+/// let yesterday = snapshot(vec![
+///     entry("CLIMBER", "DIAMOND", "II", 90),
+///     entry("FALLER", "DIAMOND", "I", 10),
+///     entry("QUITTER", "GOLD", "IV", 0),
+/// ]);
+/// let today = snapshot(vec![
+///     entry("CLIMBER", "DIAMOND", "I", 25),
+///     entry("FALLER", "DIAMOND", "II", 80),
+///     entry("NEWCOMER", "SILVER", "I", 12),
+/// ]);
+///
+/// let diff = yesterday.diff(&today);
+/// let climber = &diff.climbers()[0];
+///
+/// assert_eq!(climber.current.summoner_id, SummonerId::from("CLIMBER"));
+/// assert_eq!(climber.lp_delta(), 35);
+/// assert!(climber.is_promotion());
+/// assert!(!climber.tier_changed());
+/// assert_eq!(climber.position_delta(), 1);
+///
+/// assert!(diff.fallers()[0].is_demotion());
+/// assert_eq!(diff.added[0].summoner_id, SummonerId::from("NEWCOMER"));
+/// assert_eq!(diff.removed[0].summoner_id, SummonerId::from("QUITTER"));
+///
+/// // Tiers unknown to this crate are ranked below Iron, agreeing with the league points lost.
+/// let moved = snapshot(vec![entry("MOVED", "PLATINUM", "I", 50)])
+///     .diff(&snapshot(vec![entry("MOVED", "EMERALD", "IV", 0)]));
+/// let moved = &moved.fallers()[0];
+///
+/// assert!(moved.lp_delta() < 0);
+/// assert!(moved.is_demotion());
+/// assert!(!moved.is_promotion());
+/// ```
+#[derive(Clone, Debug)]
+pub struct LadderDiff {
+    /// Entries only on the newer ladder, in ladder order.
+    pub added: Vec<LeagueEntry>,
+    /// Entries only on the older ladder, in ladder order.
+    pub removed: Vec<LeagueEntry>,
+    /// Players on both ladders whose entry changed, in the order of the newer ladder.
+    pub changed: Vec<EntryChange>,
+}
+
+impl LadderDiff {
+    /// Compares an older snapshot of a ladder with a newer one.
+    pub fn new(previous: &LadderSnapshot, current: &LadderSnapshot) -> Self {
+        let mut added = Vec::new();
+        let mut changed = Vec::new();
+
+        for (index, entry) in current.entries().iter().enumerate() {
+            match previous.position(&entry.summoner_id) {
+                Some(previous_position) => {
+                    let change = EntryChange {
+                        previous: previous.entries()[previous_position - 1].clone(),
+                        current: entry.clone(),
+                        previous_position,
+                        current_position: index + 1,
+                    };
+
+                    if change.has_changes() {
+                        changed.push(change);
+                    }
+                }
+                None => added.push(entry.clone()),
+            }
+        }
+
+        let removed = previous
+            .entries()
+            .iter()
+            .filter(|entry| current.entry(&entry.summoner_id).is_none())
+            .cloned()
+            .collect();
+
+        LadderDiff {
+            added,
+            removed,
+            changed,
+        }
+    }
+
+    /// Changed entries that gained league points, the biggest gain first.
+    pub fn climbers(&self) -> Vec<&EntryChange> {
+        let mut climbers: Vec<&EntryChange> = self
+            .changed
+            .iter()
+            .filter(|change| change.lp_delta() > 0)
+            .collect();
+
+        climbers.sort_by_key(|change| -change.lp_delta());
+        climbers
+    }
+
+    /// Changed entries that lost league points, the biggest loss first.
+    pub fn fallers(&self) -> Vec<&EntryChange> {
+        let mut fallers: Vec<&EntryChange> = self
+            .changed
+            .iter()
+            .filter(|change| change.lp_delta() < 0)
+            .collect();
+
+        fallers.sort_by_key(|change| change.lp_delta());
+        fallers
+    }
+}
+
+/// The entry of a player on two snapshots of a ladder.
+#[derive(Clone, Debug)]
+pub struct EntryChange {
+    pub previous: LeagueEntry,
+    pub current: LeagueEntry,
+    /// Ladder position on the older snapshot, starting at 1.
+    pub previous_position: usize,
+    /// Ladder position on the newer snapshot, starting at 1.
+    pub current_position: usize,
+}
+
+impl EntryChange {
+    /// League points gained, counting promotions and demotions, see `Rank::ladder_score()`.
+    pub fn lp_delta(&self) -> i32 {
+        Rank::from(&self.previous).lp_distance(&Rank::from(&self.current))
+    }
+
+    /// Ladder positions gained, positive when the player moved up.
+    pub fn position_delta(&self) -> i64 {
+        self.previous_position as i64 - self.current_position as i64
+    }
+
+    /// Whether the player moved up a division or tier, where tiers unknown to this crate are below Iron.
+    pub fn is_promotion(&self) -> bool {
+        self.current_division() > self.previous_division()
+    }
+
+    /// Whether the player moved down a division or tier, where tiers unknown to this crate are below Iron.
+    pub fn is_demotion(&self) -> bool {
+        self.current_division() < self.previous_division()
+    }
+
+    /// Whether the player moved to another tier.
+    pub fn tier_changed(&self) -> bool {
+        self.previous.tier != self.current.tier
+    }
+
+    /// Whether the player started or ended a win streak.
+    pub fn hot_streak_changed(&self) -> bool {
+        self.previous.hot_streak != self.current.hot_streak
+    }
+
+    /// Whether the player became or stopped being a veteran of their league.
+    pub fn veteran_changed(&self) -> bool {
+        self.previous.veteran != self.current.veteran
+    }
+
+    /// Whether the player became inactive or returned from inactivity.
+    pub fn inactive_changed(&self) -> bool {
+        self.previous.inactive != self.current.inactive
+    }
+
+    /// Whether the player became or stopped being new to their league.
+    pub fn fresh_blood_changed(&self) -> bool {
+        self.previous.fresh_blood != self.current.fresh_blood
+    }
+
+    /// Whether a promotion series started, progressed or ended.
+    pub fn mini_series_changed(&self) -> bool {
+        self.previous.mini_series != self.current.mini_series
+    }
+
+    /// Games played between the two snapshots.
+    pub fn games_played(&self) -> i32 {
+        (self.current.wins + self.current.losses) - (self.previous.wins + self.previous.losses)
+    }
+
+    fn previous_division(&self) -> (i32, i32) {
+        Rank::from(&self.previous).division_key()
+    }
+
+    fn current_division(&self) -> (i32, i32) {
+        Rank::from(&self.current).division_key()
+    }
+
+    fn has_changes(&self) -> bool {
+        self.lp_delta() != 0
+            || self.tier_changed()
+            || self.previous.rank != self.current.rank
+            || self.games_played() != 0
+            || self.hot_streak_changed()
+            || self.veteran_changed()
+            || self.inactive_changed()
+            || self.fresh_blood_changed()
+            || self.mini_series_changed()
+    }
+}
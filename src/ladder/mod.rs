@@ -0,0 +1,219 @@
+//! Contains the ladder crawler, which collects the whole ranked ladder of a queue into a `LadderSnapshot`, and the
+//! `LadderDiff` between two snapshots.
+
+mod crawl;
+mod diff;
+mod snapshot;
+
+pub use crawl::{LadderCrawl, LadderPage};
+pub use diff::{EntryChange, LadderDiff};
+pub use snapshot::LadderSnapshot;
+
+use crate::{
+    enums::{Queue, Region, Tier},
+    proxy::{Result, MIN_RETRY_AFTER},
+    types::LeagueEntry,
+    Client, Error,
+};
+use crawl::league_list_entries;
+use futures_util::future::join_all;
+use log::debug;
+use std::time::{Duration, Instant};
+
+impl Client {
+    /// Access the ladder namespace that crawls whole ranked ladders.
+    ///
+    /// Use this method instead of manually constructing a `Ladder` struct.
+    /// # Example
+    ///
+    /// ```rust
+    /// # use league_of_legends_client::{Client, ClientOptions};
+    /// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    ///
+    /// // Access the ladder methods.
+    /// let ladder_api = client.ladder();
+    /// ```
+    pub fn ladder(&self) -> Ladder<'_> {
+        Ladder { client: self }
+    }
+}
+
+/// Namespace for crawling whole ranked ladders, combining the apex leagues with the paged league entries.
+///
+/// Do not create this struct manually. The preferred method is to call `client.ladder()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::{Queue, Region},
+/// #    ladder::LadderCrawl,
+/// #    Client, ClientOptions,
+/// #    Error,
+/// # };
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+/// let mut crawl = LadderCrawl::new(Region::NorthAmerica, Queue::RankedSoloQueue);
+///
+/// match client.ladder().crawl(&mut crawl).await {
+///     Ok(snapshot) => println!("{} players on the ladder", snapshot.len()),
+///     // The crawl keeps the pages crawled so far, store it to resume later.
+///     Err(error) => println!("{} pages left after: {}", crawl.pending_pages().len(), error),
+/// }
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Ladder<'a> {
+    client: &'a Client,
+}
+
+impl Ladder<'_> {
+    /// Crawls the pages left in `crawl` and returns the finished ladder.
+    ///
+    /// Up to `crawl.max_concurrency` pages are requested at the same time, paced so no more than
+    /// `crawl.max_requests_per_minute` pages are requested per minute. Being rate limited anyway, for example by other
+    /// requests made with the same API key, halves the number of concurrent requests and waits for the duration the
+    /// API asked for, after which it grows back one request at a time. Any other error stops the crawl, keeping the
+    /// progress made in `crawl` so it can be resumed by calling this method again.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::{Queue, Region},
+    /// #    ladder::LadderCrawl,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let mut crawl = LadderCrawl::new(Region::NorthAmerica, Queue::RankedSoloQueue);
+    /// let snapshot = client.ladder().crawl(&mut crawl).await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn crawl(&self, crawl: &mut LadderCrawl) -> Result<LadderSnapshot> {
+        let region = crawl.region;
+        let queue = crawl.queue.clone();
+        let mut concurrency = crawl.max_concurrency.max(1);
+        let request_interval = Duration::from_secs(60) / crawl.max_requests_per_minute.max(1);
+        let mut next_batch_at = Instant::now();
+
+        while !crawl.is_finished() {
+            tokio::time::sleep(next_batch_at.saturating_duration_since(Instant::now())).await;
+
+            let pages: Vec<LadderPage> = crawl
+                .pending_pages()
+                .iter()
+                .take(concurrency)
+                .cloned()
+                .collect();
+            next_batch_at = Instant::now() + request_interval * pages.len() as u32;
+            let results = join_all(
+                pages
+                    .iter()
+                    .map(|page| self.get_page(region, queue.clone(), page)),
+            )
+            .await;
+
+            let mut retry_after: Option<Duration> = None;
+            let mut error = None;
+
+            for (page, result) in pages.iter().zip(results) {
+                match result {
+                    Ok(entries) => crawl.complete(page, entries),
+                    Err(Error::TooManyRequests(duration)) => {
+                        let duration = duration.max(MIN_RETRY_AFTER);
+                        retry_after =
+                            Some(retry_after.map_or(duration, |longest| longest.max(duration)));
+                    }
+                    Err(page_error) => {
+                        error.get_or_insert(page_error);
+                    }
+                }
+            }
+
+            if let Some(error) = error {
+                return Err(error);
+            }
+
+            match retry_after {
+                Some(duration) => {
+                    concurrency = (concurrency / 2).max(1);
+                    debug!(
+                        "Rate limited while crawling the {} ladder of {:?}, retrying {} page(s) at a time in {:?}.",
+                        queue, region, concurrency, duration
+                    );
+                    tokio::time::sleep(duration).await;
+                }
+                None => concurrency = (concurrency + 1).min(crawl.max_concurrency.max(1)),
+            }
+        }
+
+        Ok(crawl.snapshot())
+    }
+
+    /// Crawls several ladders at the same time, usually the same queue in different regions as returned by
+    /// `LadderCrawl::for_all_regions(...)`, which are rate limited separately.
+    ///
+    /// Returns the result of each crawl in the same order.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Queue,
+    /// #    ladder::LadderCrawl,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let mut crawls = LadderCrawl::for_all_regions(Queue::RankedSoloQueue);
+    /// let snapshots = client.ladder().crawl_all(&mut crawls).await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn crawl_all(&self, crawls: &mut [LadderCrawl]) -> Vec<Result<LadderSnapshot>> {
+        join_all(crawls.iter_mut().map(|crawl| self.crawl(crawl))).await
+    }
+
+    async fn get_page(
+        &self,
+        region: Region,
+        queue: Queue,
+        page: &LadderPage,
+    ) -> Result<Vec<LeagueEntry>> {
+        let league = self.client.league();
+
+        match page {
+            LadderPage::Apex(tier) => {
+                let league_list = match tier {
+                    Tier::Challenger => league.get_challenger_league(region, queue).await?,
+                    Tier::Grandmaster => league.get_grandmaster_league(region, queue).await?,
+                    Tier::Master => league.get_master_league(region, queue).await?,
+                    tier => return Err(Error::NotApexTier(tier.clone())),
+                };
+
+                Ok(league_list_entries(league_list))
+            }
+            LadderPage::Entries {
+                tier,
+                division,
+                page,
+            } => {
+                league
+                    .get_league_entries(region, queue, tier.clone(), division.clone(), Some(*page))
+                    .await
+            }
+        }
+    }
+}
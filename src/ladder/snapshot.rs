@@ -0,0 +1,142 @@
+use super::LadderDiff;
+use crate::{
+    enums::{Queue, Region},
+    rank::Rank,
+    types::{LeagueEntry, SummonerId},
+};
+use serde::{Deserialize, Serialize};
+use std::{cmp::Reverse, collections::HashMap, time::SystemTime};
+
+/// The ranked ladder of a queue in a region at a point in time.
+///
+/// Entries are ordered from the highest to the lowest rank, ties broken by summoner id, so the ladder position of an
+/// entry is its index plus one. Players are indexed by summoner id, so looking up a single player does not scan the
+/// ladder.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::{Queue, Region},
+/// #    ladder::LadderSnapshot,
+/// #    types::{LeagueEntry, SummonerId},
+/// # };
+/// # use std::time::SystemTime;
+/// # let entry = |summoner_id: &str, tier: &str, rank: &str, league_points: i32| -> LeagueEntry {
+/// #     serde_json::from_value(serde_json::json!({
+/// #         "leagueId": "LEAGUE_ID", "summonerId": summoner_id, "summonerName": summoner_id,
+/// #         "queueType": "RANKED_SOLO_5x5", "tier": tier, "rank": rank, "leaguePoints": league_points,
+/// #         "wins": 100, "losses": 90, "hotStreak": false, "veteran": false, "freshBlood": false,
+/// #         "inactive": false
+/// #     })).unwrap()
+/// # };
+/// let snapshot = LadderSnapshot::new(
+///     Region::NorthAmerica,
+///     Queue::RankedSoloQueue,
+///     SystemTime::now(),
+///     vec![
+///         entry("DIAMOND_50", "DIAMOND", "I", 50),
+///         entry("MASTER_120", "MASTER", "I", 120),
+///         entry("DIAMOND_75", "DIAMOND", "I", 75),
+///     ],
+/// );
+///
+/// assert_eq!(snapshot.position(&SummonerId::from("MASTER_120")), Some(1));
+/// assert_eq!(snapshot.position(&SummonerId::from("DIAMOND_75")), Some(2));
+/// assert_eq!(snapshot.position(&SummonerId::from("DIAMOND_50")), Some(3));
+///
+/// // The index is rebuilt when a saved snapshot is loaded.
+/// let saved = serde_json::to_string(&snapshot).unwrap();
+/// let loaded: LadderSnapshot = serde_json::from_str(&saved).unwrap();
+///
+/// assert_eq!(loaded.entry(&SummonerId::from("DIAMOND_75")).unwrap().league_points, 75);
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(from = "SnapshotData")]
+pub struct LadderSnapshot {
+    pub region: Region,
+    pub queue: Queue,
+    /// When the crawl that produced the snapshot was started.
+    pub created_at: SystemTime,
+    entries: Vec<LeagueEntry>,
+    /// The index of each player in `entries`, rebuilt when a snapshot is deserialized.
+    #[serde(skip)]
+    index: HashMap<SummonerId, usize>,
+}
+
+/// The serialized fields of a `LadderSnapshot`, without the index.
+#[derive(Deserialize)]
+struct SnapshotData {
+    region: Region,
+    queue: Queue,
+    created_at: SystemTime,
+    entries: Vec<LeagueEntry>,
+}
+
+impl From<SnapshotData> for LadderSnapshot {
+    fn from(data: SnapshotData) -> Self {
+        let mut index = HashMap::with_capacity(data.entries.len());
+
+        for (position, entry) in data.entries.iter().enumerate() {
+            index.entry(entry.summoner_id.clone()).or_insert(position);
+        }
+
+        LadderSnapshot {
+            region: data.region,
+            queue: data.queue,
+            created_at: data.created_at,
+            entries: data.entries,
+            index,
+        }
+    }
+}
+
+impl LadderSnapshot {
+    /// Constructs a snapshot from entries in any order.
+    pub fn new(
+        region: Region,
+        queue: Queue,
+        created_at: SystemTime,
+        mut entries: Vec<LeagueEntry>,
+    ) -> Self {
+        entries.sort_by_cached_key(|entry| (Reverse(Rank::from(entry)), entry.summoner_id.clone()));
+
+        LadderSnapshot::from(SnapshotData {
+            region,
+            queue,
+            created_at,
+            entries,
+        })
+    }
+
+    /// The entries of the ladder, from the highest to the lowest rank.
+    pub fn entries(&self) -> &[LeagueEntry] {
+        &self.entries
+    }
+
+    /// The ladder position of a player, starting at 1 for the highest ranked player.
+    pub fn position(&self, summoner_id: &SummonerId) -> Option<usize> {
+        self.index.get(summoner_id).map(|index| index + 1)
+    }
+
+    /// The entry of a player.
+    pub fn entry(&self, summoner_id: &SummonerId) -> Option<&LeagueEntry> {
+        self.index
+            .get(summoner_id)
+            .map(|index| &self.entries[*index])
+    }
+
+    /// The changes from this snapshot to a newer snapshot of the same ladder, see `LadderDiff`.
+    pub fn diff(&self, newer: &LadderSnapshot) -> LadderDiff {
+        LadderDiff::new(self, newer)
+    }
+
+    /// The number of players on the ladder.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the ladder has no players.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
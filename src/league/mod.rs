@@ -1,7 +1,7 @@
 use crate::{
     enums::{Division, Queue, Region, Tier},
     proxy::{request, Result},
-    types::{LeagueEntry, LeagueList},
+    types::{LeagueEntry, LeagueList, SummonerId},
     Client, ClientContext,
 };
 use url::Url;
@@ -21,7 +21,7 @@ impl Client {
     /// // Access the league API methods.
     /// let league_api = client.league();
     /// ```
-    pub fn league(&self) -> League {
+    pub fn league(&self) -> League<'_> {
         League {
             context: &self.context,
         }
@@ -98,8 +98,7 @@ impl League<'_> {
     pub async fn get_challenger_league(&self, region: Region, queue: Queue) -> Result<LeagueList> {
         let url = Url::parse(&format!(
             "https://{}/lol/league/v4/challengerleagues/by-queue/{}",
-            region.to_string(),
-            queue.to_string()
+            region, queue
         ))?;
 
         request::<LeagueList>(url.as_str(), self.context).await
@@ -113,6 +112,7 @@ impl League<'_> {
     /// ```rust
     /// # use league_of_legends_client::{
     /// #    enums::Region,
+    /// #    types::SummonerId,
     /// #    Client, ClientOptions,
     /// #    Error,
     /// # };
@@ -124,7 +124,7 @@ impl League<'_> {
     ///     .league()
     ///     .get_league_entries_for_summoner(
     ///         Region::NorthAmerica,
-    ///         "EXAMPLE_ENCRYPTED_SUMMONER_ID".to_string(),
+    ///         SummonerId::from("EXAMPLE_ENCRYPTED_SUMMONER_ID"),
     ///     )
     ///     .await;
     /// #
@@ -134,12 +134,11 @@ impl League<'_> {
     pub async fn get_league_entries_for_summoner(
         &self,
         region: Region,
-        encrypted_summoner_id: String,
+        encrypted_summoner_id: SummonerId,
     ) -> Result<Vec<LeagueEntry>> {
         let url = Url::parse(&format!(
             "https://{}/lol/league/v4/entries/by-summoner/{}",
-            region.to_string(),
-            encrypted_summoner_id
+            region, encrypted_summoner_id
         ))?;
 
         request::<Vec<LeagueEntry>>(url.as_str(), self.context).await
@@ -149,6 +148,9 @@ impl League<'_> {
     ///
     /// API documentation can be found [here](https://developer.riotgames.com/apis#league-v4/GET_getLeagueEntries).
     ///
+    /// This endpoint does not support the master, grandmaster and challenger tiers, use
+    /// `client.league_exp().get_league_entries(...)` to page through every tier.
+    ///
     /// # Example
     /// ```rust
     /// # use league_of_legends_client::{
@@ -180,14 +182,11 @@ impl League<'_> {
         queue: Queue,
         tier: Tier,
         division: Division,
-        page: Option<i8>,
+        page: Option<i32>,
     ) -> Result<Vec<LeagueEntry>> {
         let mut url = Url::parse(&format!(
             "https://{}/lol/league/v4/entries/{}/{}/{}",
-            region.to_string(),
-            queue,
-            tier,
-            division,
+            region, queue, tier, division,
         ))?;
 
         if let Some(page) = page {
@@ -223,8 +222,7 @@ impl League<'_> {
     pub async fn get_grandmaster_league(&self, region: Region, queue: Queue) -> Result<LeagueList> {
         let url = Url::parse(&format!(
             "https://{}/lol/league/v4/grandmasterleagues/by-queue/{}",
-            region.to_string(),
-            queue.to_string()
+            region, queue
         ))?;
 
         request::<LeagueList>(url.as_str(), self.context).await
@@ -258,8 +256,7 @@ impl League<'_> {
     pub async fn get_league(&self, region: Region, league_id: String) -> Result<LeagueList> {
         let url = Url::parse(&format!(
             "https://{}/lol/league/v4/leagues/{}",
-            region.to_string(),
-            league_id
+            region, league_id
         ))?;
 
         request::<LeagueList>(url.as_str(), self.context).await
@@ -291,8 +288,7 @@ impl League<'_> {
     pub async fn get_master_league(&self, region: Region, queue: Queue) -> Result<LeagueList> {
         let url = Url::parse(&format!(
             "https://{}/lol/league/v4/masterleagues/by-queue/{}",
-            region.to_string(),
-            queue.to_string()
+            region, queue
         ))?;
 
         request::<LeagueList>(url.as_str(), self.context).await
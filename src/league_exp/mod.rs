@@ -0,0 +1,144 @@
+use crate::{
+    enums::{Division, Queue, Region, Tier},
+    proxy::{request, Result},
+    types::LeagueEntry,
+    Client, ClientContext,
+};
+use url::Url;
+
+impl Client {
+    /// Access the league exp namespace that contains experimental league related API methods.
+    ///
+    /// [Riot API documentation](https://developer.riotgames.com/apis#league-exp-v4).
+    ///
+    /// Use this method instead of manually constructing a `LeagueExp` struct.
+    /// # Example
+    ///
+    /// ```rust
+    /// # use league_of_legends_client::{Client, ClientOptions};
+    /// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    ///
+    /// // Access the league exp API methods.
+    /// let league_exp_api = client.league_exp();
+    /// ```
+    pub fn league_exp(&self) -> LeagueExp<'_> {
+        LeagueExp {
+            context: &self.context,
+        }
+    }
+}
+
+/// Namespace for the experimental league related APIs.
+///
+/// [Riot API documentation](https://developer.riotgames.com/apis#league-exp-v4).
+///
+/// Do not create this struct manually. The preferred method is to call `client.league_exp()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::{Division, Queue, Region, Tier},
+/// #    Client, ClientOptions,
+/// #    Error,
+/// # };
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+///
+/// // Access the league exp API methods.
+/// let mut challenger_entries = client
+///     .league_exp()
+///     .get_league_entries(
+///         Region::NorthAmerica,
+///         Queue::RankedSoloQueue,
+///         Tier::Challenger,
+///         Division::One,
+///         None,
+///     )
+///     .await;
+///
+/// // Alternatively use this to save some characters.
+/// let league_exp_api = client.league_exp();
+///
+/// challenger_entries = league_exp_api
+///     .get_league_entries(
+///         Region::NorthAmerica,
+///         Queue::RankedSoloQueue,
+///         Tier::Challenger,
+///         Division::One,
+///         None,
+///     )
+///     .await;
+/// let diamond_entries = league_exp_api
+///     .get_league_entries(
+///         Region::NorthAmerica,
+///         Queue::RankedSoloQueue,
+///         Tier::Diamond,
+///         Division::Four,
+///         Some(2),
+///     )
+///     .await;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct LeagueExp<'a> {
+    context: &'a ClientContext,
+}
+
+impl LeagueExp<'_> {
+    /// Get all the league entries, including the master, grandmaster and challenger tiers.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#league-exp-v4/GET_getLeagueEntries).
+    ///
+    /// The master, grandmaster and challenger tiers only have a single division, use `Division::One` for them.
+    /// Pages start at 1, an empty list is returned once there are no more entries.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::{Division, Queue, Region, Tier},
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let master_entries = client
+    ///     .league_exp()
+    ///     .get_league_entries(
+    ///         Region::NorthAmerica,
+    ///         Queue::RankedSoloQueue,
+    ///         Tier::Master,
+    ///         Division::One,
+    ///         Some(1),
+    ///     )
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_league_entries(
+        &self,
+        region: Region,
+        queue: Queue,
+        tier: Tier,
+        division: Division,
+        page: Option<i32>,
+    ) -> Result<Vec<LeagueEntry>> {
+        let mut url = Url::parse(&format!(
+            "https://{}/lol/league-exp/v4/entries/{}/{}/{}",
+            region, queue, tier, division,
+        ))?;
+
+        if let Some(page) = page {
+            url.query_pairs_mut().append_pair("page", &page.to_string());
+        }
+
+        request::<Vec<LeagueEntry>>(url.as_str(), self.context).await
+    }
+}
@@ -13,6 +13,13 @@
 //!
 //! As of right now this crate uses only tokio as its runtime.
 //!
+//! # Features
+//!
+//! * `snapshot` - Embeds a static data snapshot, available through `StaticData::bundled()`, and allows loading
+//!   newer snapshots from disk with `StaticData::from_directory(...)` for environments without network access.
+//! * `extra_fields` - Keeps response fields that are not modeled by the types in their `extra` map, so fields
+//!   added to the Riot API are not lost during ingestion.
+//!
 //! # Examples
 //!
 //! ```toml
@@ -46,19 +53,51 @@
 //! }
 //! ```
 
+mod challenges;
 mod client;
+mod data_dragon;
+#[macro_use]
 pub mod enums;
 mod error;
+pub mod ladder;
 mod league;
+mod league_exp;
+mod lor_match;
+mod lor_ranked;
 mod r#match;
+pub mod match_analysis;
 mod proxy;
+pub mod rank;
+mod static_data;
 mod summoner;
+mod tft_league;
+mod tft_match;
+mod tft_summoner;
+mod third_party_code;
+mod time;
+mod tournament;
+mod tournament_stub;
 pub mod types;
+pub mod watcher;
 
+pub use challenges::Challenges;
 use client::context::ClientContext;
 pub use client::options::ClientOptions;
 pub use client::Client;
+pub use data_dragon::DataDragon;
 pub use error::Error;
+pub use ladder::Ladder;
 pub use league::League;
+pub use league_exp::LeagueExp;
+pub use lor_match::LorMatch;
+pub use lor_ranked::LorRanked;
 pub use r#match::Match;
+pub use static_data::StaticData;
 pub use summoner::Summoner;
+pub use tft_league::TftLeague;
+pub use tft_match::TftMatch;
+pub use tft_summoner::TftSummoner;
+pub use third_party_code::ThirdPartyCode;
+pub use tournament::Tournament;
+pub use tournament_stub::TournamentStub;
+pub use watcher::Watcher;
@@ -0,0 +1,162 @@
+use crate::{
+    enums::RegionalRoute,
+    proxy::{request, Result},
+    types::{LorMatch as LorMatchDTO, MatchId, Puuid},
+    Client, ClientContext,
+};
+use url::Url;
+
+impl Client {
+    /// Access the legends of runeterra match namespace that contains match related API methods.
+    ///
+    /// [Riot API documentation](https://developer.riotgames.com/apis#lor-match-v1).
+    ///
+    /// Use this method instead of manually constructing a `LorMatch` struct.
+    /// # Example
+    ///
+    /// ```rust
+    /// # use league_of_legends_client::{Client, ClientOptions};
+    /// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    ///
+    /// // Access the legends of runeterra match API methods.
+    /// let lor_match_api = client.lor_match();
+    /// ```
+    pub fn lor_match(&self) -> LorMatch<'_> {
+        LorMatch {
+            context: &self.context,
+        }
+    }
+}
+
+/// Namespace for the legends of runeterra match related APIs.
+///
+/// [Riot API documentation](https://developer.riotgames.com/apis#lor-match-v1).
+///
+/// Matches are served by regional routing values, `Region::regional_route()` can be used to find the route for a
+/// summoner's region.
+///
+/// Legends of runeterra is only served by the americas, europe and south east asia regional routes, players in
+/// asia are served by `RegionalRoute::SouthEastAsia`.
+///
+/// Do not create this struct manually. The preferred method is to call `client.lor_match()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::{Region, RegionalRoute},
+/// #    types::MatchId,
+/// #    types::Puuid,
+/// #    Client, ClientOptions,
+/// #    Error,
+/// # };
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+///
+/// // Access the legends of runeterra match API methods.
+/// let mut game = client
+///     .lor_match()
+///     .get_match_by_id(RegionalRoute::Americas, MatchId::from("EXAMPLE_MATCH_ID"))
+///     .await;
+///
+/// // Alternatively use this to save some characters.
+/// let lor_match_api = client.lor_match();
+///
+/// game = lor_match_api
+///     .get_match_by_id(Region::NorthAmerica.regional_route(), MatchId::from("EXAMPLE_MATCH_ID"))
+///     .await;
+/// let match_ids = lor_match_api
+///     .get_match_ids_by_puuid(
+///         RegionalRoute::Americas,
+///         Puuid::from("EXAMPLE_PUUID"),
+///     )
+///     .await;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct LorMatch<'a> {
+    context: &'a ClientContext,
+}
+
+impl LorMatch<'_> {
+    /// Get a list of match ids by PUUID.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#lor-match-v1/GET_getMatchIdsByPUUID).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::RegionalRoute,
+    /// #    types::Puuid,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let match_ids = client
+    ///     .lor_match()
+    ///     .get_match_ids_by_puuid(
+    ///         RegionalRoute::Americas,
+    ///         Puuid::from("EXAMPLE_PUUID"),
+    ///     )
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_match_ids_by_puuid(
+        &self,
+        regional_route: RegionalRoute,
+        puuid: Puuid,
+    ) -> Result<Vec<MatchId>> {
+        let url = Url::parse(&format!(
+            "https://{}/lor/match/v1/matches/by-puuid/{}/ids",
+            regional_route, puuid
+        ))?;
+
+        request::<Vec<MatchId>>(url.as_str(), self.context).await
+    }
+
+    /// Get a match by match id.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#lor-match-v1/GET_getMatch).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::RegionalRoute,
+    /// #    types::MatchId,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let game = client
+    ///     .lor_match()
+    ///     .get_match_by_id(RegionalRoute::Americas, MatchId::from("EXAMPLE_MATCH_ID"))
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_match_by_id(
+        &self,
+        regional_route: RegionalRoute,
+        match_id: MatchId,
+    ) -> Result<LorMatchDTO> {
+        let url = Url::parse(&format!(
+            "https://{}/lor/match/v1/matches/{}",
+            regional_route, match_id
+        ))?;
+
+        request::<LorMatchDTO>(url.as_str(), self.context).await
+    }
+}
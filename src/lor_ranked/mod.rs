@@ -0,0 +1,106 @@
+use crate::{
+    enums::RegionalRoute,
+    proxy::{request, Result},
+    types::LorLeaderboard,
+    Client, ClientContext,
+};
+use url::Url;
+
+impl Client {
+    /// Access the legends of runeterra ranked namespace that contains ranked related API methods.
+    ///
+    /// [Riot API documentation](https://developer.riotgames.com/apis#lor-ranked-v1).
+    ///
+    /// Use this method instead of manually constructing a `LorRanked` struct.
+    /// # Example
+    ///
+    /// ```rust
+    /// # use league_of_legends_client::{Client, ClientOptions};
+    /// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    ///
+    /// // Access the legends of runeterra ranked API methods.
+    /// let lor_ranked_api = client.lor_ranked();
+    /// ```
+    pub fn lor_ranked(&self) -> LorRanked<'_> {
+        LorRanked {
+            context: &self.context,
+        }
+    }
+}
+
+/// Namespace for the legends of runeterra ranked related APIs.
+///
+/// [Riot API documentation](https://developer.riotgames.com/apis#lor-ranked-v1).
+///
+/// Legends of runeterra is only served by the americas, europe and south east asia regional routes, players in
+/// asia are served by `RegionalRoute::SouthEastAsia`.
+///
+/// Do not create this struct manually. The preferred method is to call `client.lor_ranked()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::RegionalRoute,
+/// #    Client, ClientOptions,
+/// #    Error,
+/// # };
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+///
+/// // Access the legends of runeterra ranked API methods.
+/// let mut leaderboard = client
+///     .lor_ranked()
+///     .get_leaderboards(RegionalRoute::Americas)
+///     .await;
+///
+/// // Alternatively use this to save some characters.
+/// let lor_ranked_api = client.lor_ranked();
+///
+/// leaderboard = lor_ranked_api
+///     .get_leaderboards(RegionalRoute::Europe)
+///     .await;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct LorRanked<'a> {
+    context: &'a ClientContext,
+}
+
+impl LorRanked<'_> {
+    /// Get the players in Master tier.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#lor-ranked-v1/GET_getLeaderboards).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::RegionalRoute,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let leaderboard = client
+    ///     .lor_ranked()
+    ///     .get_leaderboards(RegionalRoute::Americas)
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_leaderboards(&self, regional_route: RegionalRoute) -> Result<LorLeaderboard> {
+        let url = Url::parse(&format!(
+            "https://{}/lor/ranked/v1/leaderboards",
+            regional_route
+        ))?;
+
+        request::<LorLeaderboard>(url.as_str(), self.context).await
+    }
+}
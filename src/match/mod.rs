@@ -1,10 +1,11 @@
 use crate::{
-    enums::Region,
+    enums::{QueueId, Region},
     proxy::{request, Result},
-    types::{Match as MatchDTO, MatchList},
+    time::to_epoch_millis,
+    types::{AccountId, GameId, Match as MatchDTO, MatchList},
     Client, ClientContext,
 };
-use std::collections::HashSet;
+use std::{collections::HashSet, time::SystemTime};
 use url::Url;
 
 impl Client {
@@ -24,7 +25,7 @@ impl Client {
     /// // Access the match API methods.
     /// let match_api = client.r#match();
     /// ```
-    pub fn r#match(&self) -> Match {
+    pub fn r#match(&self) -> Match<'_> {
         Match {
             context: &self.context,
         }
@@ -44,6 +45,7 @@ impl Client {
 /// ```rust
 /// # use league_of_legends_client::{
 /// #    enums::Region,
+/// #    types::{AccountId, GameId},
 /// #    Client, ClientOptions,
 /// #    Error,
 /// # };
@@ -55,19 +57,19 @@ impl Client {
 /// // Access the match API methods.
 /// let mut game = client
 ///     .r#match()
-///     .get_match_by_id(Region::NorthAmerica, 00000)
+///     .get_match_by_id(Region::NorthAmerica, GameId::from(00000))
 ///     .await;
 ///
 /// // Alternatively use this to save some characters.
 /// let match_api = client.r#match();
 ///
 /// game = match_api
-///     .get_match_by_id(Region::NorthAmerica, 00000)
+///     .get_match_by_id(Region::NorthAmerica, GameId::from(00000))
 ///     .await;
 /// let match_list = match_api
 ///     .get_match_list_by_account(
 ///         Region::NorthAmerica,
-///         "EXAMPLE_ENCRYPTED_ACCOUNT_ID".to_string(),
+///         AccountId::from("EXAMPLE_ENCRYPTED_ACCOUNT_ID"),
 ///         None,
 ///         None,
 ///         None,
@@ -94,6 +96,7 @@ impl Match<'_> {
     /// ```rust
     /// # use league_of_legends_client::{
     /// #    enums::Region,
+    /// #    types::GameId,
     /// #    Client, ClientOptions,
     /// #    Error,
     /// # };
@@ -103,17 +106,16 @@ impl Match<'_> {
     /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
     /// let game = client
     ///     .r#match()
-    ///     .get_match_by_id(Region::NorthAmerica, 00000)
+    ///     .get_match_by_id(Region::NorthAmerica, GameId::from(00000))
     ///     .await;
     /// #
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_match_by_id(&self, region: Region, match_id: i64) -> Result<MatchDTO> {
+    pub async fn get_match_by_id(&self, region: Region, match_id: GameId) -> Result<MatchDTO> {
         let url = Url::parse(&format!(
             "https://{}/lol/match/v4/matches/{}",
-            region.to_string(),
-            match_id.to_string()
+            region, match_id
         ))?;
 
         request::<MatchDTO>(url.as_str(), self.context).await
@@ -139,23 +141,26 @@ impl Match<'_> {
     /// # Example
     /// ```rust
     /// # use league_of_legends_client::{
-    /// #    enums::Region,
+    /// #    enums::{QueueId, Region},
+    /// #    types::AccountId,
     /// #    Client, ClientOptions,
     /// #    Error,
     /// # };
+    /// # use std::time::{Duration, SystemTime};
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Error> {
     /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let now = SystemTime::now();
     /// let game = client
     ///     .r#match()
     ///     .get_match_list_by_account(
     ///         Region::NorthAmerica,
-    ///         "EXAMPLE_ENCRYPTED_ACCOUNT_ID".to_string(),
-    ///         None,
-    ///         None,
-    ///         None,
+    ///         AccountId::from("EXAMPLE_ENCRYPTED_ACCOUNT_ID"),
     ///         None,
+    ///         Some([QueueId::RankedSolo, QueueId::RankedFlex].iter().copied().collect()),
+    ///         Some(now),
+    ///         Some(now - Duration::from_secs(7 * 24 * 60 * 60)),
     ///         None,
     ///         None,
     ///     )
@@ -164,21 +169,21 @@ impl Match<'_> {
     /// # Ok(())
     /// # }
     /// ```
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_match_list_by_account(
         &self,
         region: Region,
-        encrypted_account_id: String,
+        encrypted_account_id: AccountId,
         champion: Option<HashSet<i32>>,
-        queue: Option<HashSet<i32>>,
-        end_time: Option<i64>,
-        begin_time: Option<i64>,
+        queue: Option<HashSet<QueueId>>,
+        end_time: Option<SystemTime>,
+        begin_time: Option<SystemTime>,
         end_index: Option<i32>,
         begin_index: Option<i32>,
     ) -> Result<MatchList> {
         let mut url = Url::parse(&format!(
             "https://{}/lol/match/v4/matchlists/by-account/{}",
-            region.to_string(),
-            encrypted_account_id
+            region, encrypted_account_id
         ))?;
 
         if let Some(champion) = champion {
@@ -197,12 +202,12 @@ impl Match<'_> {
 
         if let Some(end_time) = end_time {
             url.query_pairs_mut()
-                .append_pair("endTime", &end_time.to_string());
+                .append_pair("endTime", &to_epoch_millis(end_time).to_string());
         }
 
         if let Some(begin_time) = begin_time {
             url.query_pairs_mut()
-                .append_pair("beginTime", &begin_time.to_string());
+                .append_pair("beginTime", &to_epoch_millis(begin_time).to_string());
         }
 
         if let Some(end_index) = end_index {
@@ -0,0 +1,175 @@
+//! Contains derived per-participant statistics such as KDA, CS per minute and kill participation, and their
+//! aggregation over a player's match history.
+
+mod performance;
+mod positions;
+
+pub use performance::{PerformanceAggregator, PerformanceSummary, PlayerIdentity};
+pub use positions::positions;
+
+use crate::types::{Match, Participant, Team};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Games shorter than this are treated as remakes, whose statistics are not meaningful.
+pub const REMAKE_THRESHOLD: Duration = Duration::from_secs(5 * 60);
+
+/// Whether the game ended before `REMAKE_THRESHOLD`, e.g. because it was remade.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::{match_analysis::{is_remake, ParticipantMetrics}, types::Match};
+/// # let mut game: Match = serde_json::from_str(&std::fs::read_to_string(
+/// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+/// # ).unwrap()).unwrap();
+/// assert!(!is_remake(&game));
+///
+/// game.game_duration = 200;
+///
+/// assert!(is_remake(&game));
+/// assert!(ParticipantMetrics::all(&game).is_empty());
+/// ```
+pub fn is_remake(game: &Match) -> bool {
+    game.duration() < REMAKE_THRESHOLD
+}
+
+/// Totals of a single team, summed over its participants.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct TeamTotals {
+    pub kills: i32,
+    pub deaths: i32,
+    pub assists: i32,
+    pub damage_dealt_to_champions: i64,
+    pub gold_earned: i32,
+    pub vision_score: i64,
+}
+
+impl TeamTotals {
+    /// Sums the statistics of every participant of `team` in `game`.
+    pub fn new(game: &Match, team: &Team) -> Self {
+        game.participants
+            .iter()
+            .filter(|participant| &participant.team_id == team)
+            .fold(TeamTotals::default(), |mut totals, participant| {
+                let stats = &participant.stats;
+
+                totals.kills += stats.kills;
+                totals.deaths += stats.deaths;
+                totals.assists += stats.assists;
+                totals.damage_dealt_to_champions += stats.total_damage_dealt_to_champions;
+                totals.gold_earned += stats.gold_earned;
+                totals.vision_score += stats.vision_score;
+                totals
+            })
+    }
+}
+
+/// Statistics of a participant derived from their `ParticipantStats`, the game duration and their team's totals.
+///
+/// Ratios whose denominator is zero, such as the kill participation of a team without kills, are reported as `0.0`.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::{match_analysis::ParticipantMetrics, types::Match};
+/// # let game: Match = serde_json::from_str(&std::fs::read_to_string(
+/// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+/// # ).unwrap()).unwrap();
+/// let metrics = ParticipantMetrics::all(&game);
+/// let jungler = metrics
+///     .iter()
+///     .find(|metrics| metrics.participant_id == 2)
+///     .unwrap();
+///
+/// // 8 kills, 2 deaths and 10 assists out of the 25 kills of their team.
+/// assert_eq!(jungler.kda, 9.0);
+/// assert_eq!(jungler.kill_participation, 0.72);
+/// assert_eq!(jungler.creep_score, 38 + 152);
+/// ```
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ParticipantMetrics {
+    pub participant_id: i32,
+    /// Kills plus assists per death, where deathless games count as a single death.
+    pub kda: f64,
+    /// Whether the participant did not die, in which case `kda` is their kills plus assists.
+    pub perfect_kda: bool,
+    /// Lane minions plus neutral monsters killed.
+    pub creep_score: i32,
+    pub cs_per_minute: f64,
+    pub gold_per_minute: f64,
+    /// Share of the team's kills the participant took part in, between `0.0` and `1.0`.
+    pub kill_participation: f64,
+    /// Share of the team's damage to champions dealt by the participant, between `0.0` and `1.0`.
+    pub damage_share: f64,
+    pub vision_score_per_minute: f64,
+    /// Damage to champions per gold earned.
+    pub damage_per_gold: f64,
+}
+
+impl ParticipantMetrics {
+    /// Derives the metrics of `participant` in `game`.
+    ///
+    /// Returns `None` for remakes, see `is_remake`.
+    pub fn new(game: &Match, participant: &Participant) -> Option<Self> {
+        if is_remake(game) {
+            return None;
+        }
+
+        let team = TeamTotals::new(game, &participant.team_id);
+
+        Some(ParticipantMetrics::with_team_totals(
+            game,
+            participant,
+            &team,
+        ))
+    }
+
+    /// Derives the metrics of every participant in `game`, or none for remakes.
+    pub fn all(game: &Match) -> Vec<Self> {
+        if is_remake(game) {
+            return Vec::new();
+        }
+
+        game.participants
+            .iter()
+            .map(|participant| {
+                let team = TeamTotals::new(game, &participant.team_id);
+
+                ParticipantMetrics::with_team_totals(game, participant, &team)
+            })
+            .collect()
+    }
+
+    fn with_team_totals(game: &Match, participant: &Participant, team: &TeamTotals) -> Self {
+        let stats = &participant.stats;
+        let minutes = game.duration().as_secs_f64() / 60.0;
+        let creep_score = stats.total_minions_killed + stats.neutral_minions_killed;
+        let takedowns = f64::from(stats.kills + stats.assists);
+
+        ParticipantMetrics {
+            participant_id: participant.participant_id,
+            kda: takedowns / f64::from(stats.deaths.max(1)),
+            perfect_kda: stats.deaths == 0,
+            creep_score,
+            cs_per_minute: ratio(f64::from(creep_score), minutes),
+            gold_per_minute: ratio(f64::from(stats.gold_earned), minutes),
+            kill_participation: ratio(takedowns, f64::from(team.kills)),
+            damage_share: ratio(
+                stats.total_damage_dealt_to_champions as f64,
+                team.damage_dealt_to_champions as f64,
+            ),
+            vision_score_per_minute: ratio(stats.vision_score as f64, minutes),
+            damage_per_gold: ratio(
+                stats.total_damage_dealt_to_champions as f64,
+                f64::from(stats.gold_earned),
+            ),
+        }
+    }
+}
+
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator > 0.0 {
+        numerator / denominator
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,305 @@
+use super::is_remake;
+use crate::{
+    enums::{Position, QueueId},
+    types::{AccountId, GameId, Match, MatchPlayer, PlatformId, SummonerId},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+/// Identifies the player whose performance is aggregated.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum PlayerIdentity {
+    SummonerId(SummonerId),
+    AccountId(AccountId),
+}
+
+impl PlayerIdentity {
+    fn find<'a>(&self, game: &'a Match) -> Option<MatchPlayer<'a>> {
+        match self {
+            PlayerIdentity::SummonerId(summoner_id) => game.player_by_summoner_id(summoner_id),
+            PlayerIdentity::AccountId(account_id) => game.player_by_account_id(account_id),
+        }
+    }
+}
+
+impl From<SummonerId> for PlayerIdentity {
+    fn from(summoner_id: SummonerId) -> Self {
+        PlayerIdentity::SummonerId(summoner_id)
+    }
+}
+
+impl From<AccountId> for PlayerIdentity {
+    fn from(account_id: AccountId) -> Self {
+        PlayerIdentity::AccountId(account_id)
+    }
+}
+
+/// Totals of a player over a set of games, from which averages are derived.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PerformanceSummary {
+    pub games: u32,
+    pub wins: u32,
+    pub kills: i64,
+    pub deaths: i64,
+    pub assists: i64,
+    pub creep_score: i64,
+    pub gold_earned: i64,
+    pub damage_dealt_to_champions: i64,
+    pub vision_score: i64,
+    pub time_played: Duration,
+}
+
+impl PerformanceSummary {
+    /// Adds the statistics of a single game.
+    pub fn add(&mut self, game: &Match, player: &MatchPlayer) {
+        let stats = &player.participant.stats;
+
+        self.games += 1;
+        self.wins += u32::from(stats.win);
+        self.kills += i64::from(stats.kills);
+        self.deaths += i64::from(stats.deaths);
+        self.assists += i64::from(stats.assists);
+        self.creep_score += i64::from(stats.total_minions_killed + stats.neutral_minions_killed);
+        self.gold_earned += i64::from(stats.gold_earned);
+        self.damage_dealt_to_champions += stats.total_damage_dealt_to_champions;
+        self.vision_score += stats.vision_score;
+        self.time_played += game.duration();
+    }
+
+    /// Adds the totals of another summary.
+    pub fn merge(&mut self, other: &PerformanceSummary) {
+        self.games += other.games;
+        self.wins += other.wins;
+        self.kills += other.kills;
+        self.deaths += other.deaths;
+        self.assists += other.assists;
+        self.creep_score += other.creep_score;
+        self.gold_earned += other.gold_earned;
+        self.damage_dealt_to_champions += other.damage_dealt_to_champions;
+        self.vision_score += other.vision_score;
+        self.time_played += other.time_played;
+    }
+
+    /// Games lost.
+    pub fn losses(&self) -> u32 {
+        self.games - self.wins
+    }
+
+    /// Share of games won, between `0.0` and `1.0`.
+    pub fn win_rate(&self) -> f64 {
+        self.per_game(i64::from(self.wins))
+    }
+
+    /// Kills plus assists per death over all games, where deathless histories count as a single death.
+    pub fn kda(&self) -> f64 {
+        (self.kills + self.assists) as f64 / self.deaths.max(1) as f64
+    }
+
+    /// Kills per game.
+    pub fn average_kills(&self) -> f64 {
+        self.per_game(self.kills)
+    }
+
+    /// Deaths per game.
+    pub fn average_deaths(&self) -> f64 {
+        self.per_game(self.deaths)
+    }
+
+    /// Assists per game.
+    pub fn average_assists(&self) -> f64 {
+        self.per_game(self.assists)
+    }
+
+    /// Damage to champions per game.
+    pub fn average_damage_dealt_to_champions(&self) -> f64 {
+        self.per_game(self.damage_dealt_to_champions)
+    }
+
+    /// Vision score per game.
+    pub fn average_vision_score(&self) -> f64 {
+        self.per_game(self.vision_score)
+    }
+
+    /// Lane minions plus neutral monsters killed per minute played.
+    pub fn cs_per_minute(&self) -> f64 {
+        self.per_minute(self.creep_score)
+    }
+
+    /// Gold earned per minute played.
+    pub fn gold_per_minute(&self) -> f64 {
+        self.per_minute(self.gold_earned)
+    }
+
+    fn per_game(&self, total: i64) -> f64 {
+        if self.games == 0 {
+            return 0.0;
+        }
+
+        total as f64 / f64::from(self.games)
+    }
+
+    fn per_minute(&self, total: i64) -> f64 {
+        let minutes = self.time_played.as_secs_f64() / 60.0;
+
+        if minutes > 0.0 {
+            total as f64 / minutes
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Aggregates the performance of a single player over their match history, per champion, queue and position.
+///
+/// Matches can be added incrementally as they arrive, and the aggregator can be serialized to continue in a later run.
+/// Matches the player is not part of, remakes and matches that were already added are skipped, identifying matches by
+/// their platform and game id.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::{Position, QueueId},
+/// #    match_analysis::PerformanceAggregator,
+/// #    types::{Match, SummonerId},
+/// # };
+/// # let game: Match = serde_json::from_str(&std::fs::read_to_string(
+/// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+/// # ).unwrap()).unwrap();
+/// let mut flex_game = game.clone();
+/// flex_game.game_id.0 += 1;
+/// flex_game.queue_id = QueueId::RankedFlex;
+///
+/// let mut performance = PerformanceAggregator::new(SummonerId::from("SUMMONER_ID_02"));
+///
+/// assert!(performance.add_match(&game));
+/// assert!(!performance.add_match(&game));
+/// assert!(performance.add_match(&flex_game));
+///
+/// let champion = &performance.by_champion[&64];
+///
+/// assert_eq!(champion.games, 2);
+/// assert_eq!(champion.win_rate(), 1.0);
+/// assert_eq!(champion.kda(), 9.0);
+/// assert_eq!(performance.by_queue[&QueueId::RankedSolo].games, 1);
+/// assert_eq!(performance.by_queue[&QueueId::RankedFlex].games, 1);
+/// assert_eq!(performance.by_position[&Position::Jungle].games, 2);
+///
+/// let saved = serde_json::to_string(&performance).unwrap();
+/// let mut performance: PerformanceAggregator = serde_json::from_str(&saved).unwrap();
+///
+/// assert!(!performance.add_match(&flex_game));
+/// assert_eq!(performance.len(), 2);
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PerformanceAggregator {
+    pub identity: PlayerIdentity,
+    pub overall: PerformanceSummary,
+    /// Summaries keyed by champion id.
+    pub by_champion: HashMap<i32, PerformanceSummary>,
+    pub by_queue: HashMap<QueueId, PerformanceSummary>,
+    /// Summaries keyed by the position of the player, see `match_analysis::positions`.
+    pub by_position: HashMap<Position, PerformanceSummary>,
+    game_ids: HashSet<(PlatformId, GameId)>,
+}
+
+impl PerformanceAggregator {
+    /// Constructs an empty aggregator for the player with the given identity.
+    pub fn new(identity: impl Into<PlayerIdentity>) -> Self {
+        PerformanceAggregator {
+            identity: identity.into(),
+            overall: PerformanceSummary::default(),
+            by_champion: HashMap::new(),
+            by_queue: HashMap::new(),
+            by_position: HashMap::new(),
+            game_ids: HashSet::new(),
+        }
+    }
+
+    /// Adds a single match, returning whether it was counted.
+    pub fn add_match(&mut self, game: &Match) -> bool {
+        let key = (game.platform_id.clone(), game.game_id);
+
+        if is_remake(game) || self.game_ids.contains(&key) {
+            return false;
+        }
+
+        let player = match self.identity.find(game) {
+            Some(player) => player,
+            None => return false,
+        };
+
+        self.overall.add(game, &player);
+        self.by_champion
+            .entry(player.participant.champion_id)
+            .or_default()
+            .add(game, &player);
+        self.by_queue
+            .entry(game.queue_id)
+            .or_default()
+            .add(game, &player);
+
+        if let Some(position) = player.position() {
+            self.by_position
+                .entry(position)
+                .or_default()
+                .add(game, &player);
+        }
+
+        self.game_ids.insert(key);
+
+        true
+    }
+
+    /// Adds every match of `games`, returning how many were counted.
+    pub fn add_matches<'a>(&mut self, games: impl IntoIterator<Item = &'a Match>) -> usize {
+        games
+            .into_iter()
+            .filter(|game| self.add_match(game))
+            .count()
+    }
+
+    /// Merges the summaries of another aggregator of the same player, skipping it entirely if both counted any of the
+    /// same matches, as the overlap cannot be separated from the totals. Returns whether it was merged.
+    pub fn merge(&mut self, other: &PerformanceAggregator) -> bool {
+        if self.identity != other.identity || !self.game_ids.is_disjoint(&other.game_ids) {
+            return false;
+        }
+
+        self.overall.merge(&other.overall);
+
+        for (champion_id, summary) in &other.by_champion {
+            self.by_champion
+                .entry(*champion_id)
+                .or_default()
+                .merge(summary);
+        }
+
+        for (queue_id, summary) in &other.by_queue {
+            self.by_queue.entry(*queue_id).or_default().merge(summary);
+        }
+
+        for (position, summary) in &other.by_position {
+            self.by_position
+                .entry(*position)
+                .or_default()
+                .merge(summary);
+        }
+
+        self.game_ids.extend(other.game_ids.iter().cloned());
+
+        true
+    }
+
+    /// The number of matches counted so far.
+    pub fn len(&self) -> usize {
+        self.game_ids.len()
+    }
+
+    /// Whether no match was counted yet.
+    pub fn is_empty(&self) -> bool {
+        self.game_ids.is_empty()
+    }
+}
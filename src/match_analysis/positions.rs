@@ -0,0 +1,198 @@
+use crate::{
+    enums::Position,
+    types::{Match, Participant, Role, Team},
+};
+use std::collections::HashMap;
+
+/// Positions in the order their scores are stored in.
+const POSITIONS: [Position; 5] = [
+    Position::Top,
+    Position::Jungle,
+    Position::Middle,
+    Position::Bottom,
+    Position::Utility,
+];
+/// The map id of Summoner's Rift, the only map with lanes.
+const SUMMONERS_RIFT: i32 = 11;
+/// The summoner spell id of smite.
+const SMITE: i32 = 11;
+/// Item ids of the support quest items and their upgrades.
+const SUPPORT_ITEMS: [i32; 12] = [
+    3850, 3851, 3853, 3854, 3855, 3857, 3858, 3859, 3860, 3862, 3863, 3864,
+];
+
+/// Assigns each participant of `game` a position, keyed by participant id.
+///
+/// The lane and role reported by match v4 are unreliable, so they are weighed against summoner spells, creep score and
+/// support items. Teams of at most five participants get each position at most once, picking the assignment that fits
+/// the team best as a whole. Games on maps other than Summoner's Rift have no positions, so the map is empty.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::{enums::Position, match_analysis::positions, types::Match};
+/// # let game: Match = serde_json::from_str(&std::fs::read_to_string(
+/// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+/// # ).unwrap()).unwrap();
+/// // Howling Abyss has a single lane, so its games have no positions.
+/// let aram = Match { map_id: 12, ..game.clone() };
+///
+/// assert!(positions(&aram).is_empty());
+///
+/// let positions = positions(&game);
+///
+/// // Both bottom laners of the red team are reported as BOTTOM + DUO, the one with the support item supports.
+/// assert_eq!(positions[&9], Position::Bottom);
+/// assert_eq!(positions[&10], Position::Utility);
+/// assert_eq!(positions[&7], Position::Jungle);
+/// ```
+pub fn positions(game: &Match) -> HashMap<i32, Position> {
+    if game.map_id != SUMMONERS_RIFT {
+        return HashMap::new();
+    }
+
+    let mut teams: Vec<&Team> = Vec::new();
+
+    for participant in &game.participants {
+        if !teams.contains(&&participant.team_id) {
+            teams.push(&participant.team_id);
+        }
+    }
+
+    let mut positions = HashMap::new();
+
+    for team in teams {
+        let members: Vec<&Participant> = game
+            .participants
+            .iter()
+            .filter(|participant| &participant.team_id == team)
+            .collect();
+        let least_minions = members
+            .iter()
+            .filter(|participant| !has_smite(participant))
+            .min_by_key(|participant| participant.stats.total_minions_killed)
+            .map(|participant| participant.participant_id);
+        let scores: Vec<[i32; 5]> = members
+            .iter()
+            .map(|participant| {
+                let least_minions = least_minions == Some(participant.participant_id);
+
+                let mut scores = [0; 5];
+                for (score, position) in scores.iter_mut().zip(POSITIONS.iter()) {
+                    *score = score_position(participant, *position, least_minions);
+                }
+                scores
+            })
+            .collect();
+
+        for (participant, position) in members.iter().zip(assign(&scores)) {
+            positions.insert(participant.participant_id, position);
+        }
+    }
+
+    positions
+}
+
+fn has_smite(participant: &Participant) -> bool {
+    participant.spell_1_id == SMITE || participant.spell_2_id == SMITE
+}
+
+/// How well `position` fits the participant, higher is better.
+fn score_position(participant: &Participant, position: Position, least_minions: bool) -> i32 {
+    let stats = &participant.stats;
+    let timeline = &participant.timeline;
+    let lane = timeline.lane.position();
+    let mut score = 0;
+
+    if lane == Some(position) {
+        score += 4;
+    }
+
+    match position {
+        Position::Jungle => {
+            if has_smite(participant) {
+                score += 10;
+            }
+
+            if stats.neutral_minions_killed > stats.total_minions_killed {
+                score += 3;
+            }
+        }
+        _ if has_smite(participant) => score -= 5,
+        _ => {}
+    }
+
+    match (&timeline.role, position) {
+        (Role::DuoCarry, Position::Bottom) | (Role::DuoSupport, Position::Utility) => score += 3,
+        _ => {}
+    }
+
+    if position == Position::Utility {
+        // Supports share the bottom lane and farm the least.
+        if lane == Some(Position::Bottom) {
+            score += 2;
+        }
+
+        if least_minions {
+            score += 2;
+        }
+
+        let items = [
+            stats.item_0,
+            stats.item_1,
+            stats.item_2,
+            stats.item_3,
+            stats.item_4,
+            stats.item_5,
+        ];
+
+        if items.iter().any(|item| SUPPORT_ITEMS.contains(item)) {
+            score += 5;
+        }
+    }
+
+    score
+}
+
+/// Picks the position of each participant from their scores, each at most once if there are enough positions.
+fn assign(scores: &[[i32; 5]]) -> Vec<Position> {
+    if scores.len() > POSITIONS.len() {
+        return scores
+            .iter()
+            .map(|scores| {
+                let best = (0..POSITIONS.len())
+                    .max_by_key(|index| (scores[*index], -(*index as i32)))
+                    .unwrap_or_default();
+
+                POSITIONS[best]
+            })
+            .collect();
+    }
+
+    let mut best = (i32::MIN, Vec::new());
+
+    search(scores, &mut Vec::new(), 0, &mut best);
+
+    best.1.into_iter().map(|index| POSITIONS[index]).collect()
+}
+
+/// Exhaustively searches the distinct position assignments for the one with the highest total score.
+fn search(scores: &[[i32; 5]], chosen: &mut Vec<usize>, total: i32, best: &mut (i32, Vec<usize>)) {
+    let row = match scores.get(chosen.len()) {
+        Some(row) => row,
+        None => {
+            if total > best.0 {
+                *best = (total, chosen.clone());
+            }
+
+            return;
+        }
+    };
+
+    for (index, score) in row.iter().enumerate() {
+        if !chosen.contains(&index) {
+            chosen.push(index);
+            search(scores, chosen, total + score, best);
+            chosen.pop();
+        }
+    }
+}
@@ -3,8 +3,11 @@ mod rate_limit;
 use crate::{ClientContext, Error};
 use log::{debug, error};
 use rate_limit::RateLimit;
-use reqwest::{header::HeaderMap, Response, StatusCode};
-use serde::de::DeserializeOwned;
+pub use rate_limit::MIN_RETRY_AFTER;
+use reqwest::{
+    header::HeaderMap, Client as HttpClient, Method, RequestBuilder, Response, StatusCode,
+};
+use serde::{de::DeserializeOwned, Serialize};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -15,11 +18,11 @@ fn parse_headers(header_map: &HeaderMap) -> RateLimit {
     rate_limit
 }
 
-async fn handle_response<T: DeserializeOwned>(response: Response) -> Result<T> {
+fn handle_response(response: Response) -> Result<Response> {
     let rate_limit = parse_headers(response.headers());
 
     match response.error_for_status() {
-        Ok(response) => Ok(response.json::<T>().await?),
+        Ok(response) => Ok(response),
         Err(error) => {
             error!("API came back with error response: {}", error);
             match error.status() {
@@ -84,7 +87,12 @@ async fn handle_response<T: DeserializeOwned>(response: Response) -> Result<T> {
     }
 }
 
-pub async fn request<T: DeserializeOwned>(url: &str, context: &ClientContext) -> Result<T> {
+async fn send<B: Serialize + ?Sized>(
+    method: Method,
+    url: &str,
+    body: Option<&B>,
+    context: &ClientContext,
+) -> Result<Response> {
     let mut headers = HeaderMap::new();
     headers.append(
         "X-Riot-Token",
@@ -95,20 +103,62 @@ pub async fn request<T: DeserializeOwned>(url: &str, context: &ClientContext) ->
             .expect("Unable to parse X-Riot-Token to header."),
     );
 
-    let client = &context.http_client;
+    let build_request = || -> RequestBuilder {
+        let request_builder = context
+            .http_client
+            .request(method.clone(), url)
+            .headers(headers.clone());
+        match body {
+            Some(body) => request_builder.json(body),
+            None => request_builder,
+        }
+    };
 
-    let response =
-        handle_response::<T>(client.get(url).headers(headers.clone()).send().await?).await;
+    let response = handle_response(build_request().send().await?);
 
     if context.options.retry {
         if let Err(Error::TooManyRequests(duration)) = response {
             tokio::time::sleep(duration).await;
-            return handle_response::<T>(client.get(url).headers(headers.clone()).send().await?)
-                .await;
-        } else {
-            return response;
+            return handle_response(build_request().send().await?);
         }
-    } else {
-        response
     }
+
+    response
+}
+
+/// Sends a GET request and deserializes the JSON response.
+pub async fn request<T: DeserializeOwned>(url: &str, context: &ClientContext) -> Result<T> {
+    let response = send::<()>(Method::GET, url, None, context).await?;
+    Ok(response.json::<T>().await?)
+}
+
+/// Sends a request with a JSON body and deserializes the JSON response.
+pub async fn request_with_body<B: Serialize + ?Sized, T: DeserializeOwned>(
+    method: Method,
+    url: &str,
+    body: &B,
+    context: &ClientContext,
+) -> Result<T> {
+    let response = send(method, url, Some(body), context).await?;
+    Ok(response.json::<T>().await?)
+}
+
+/// Sends a request with a JSON body for endpoints that do not return a response body.
+pub async fn request_without_response<B: Serialize + ?Sized>(
+    method: Method,
+    url: &str,
+    body: &B,
+    context: &ClientContext,
+) -> Result<()> {
+    send(method, url, Some(body), context).await?;
+    Ok(())
+}
+
+/// Sends a GET request without the riot api key, for static data that is not served by the riot api.
+pub async fn request_without_token<T: DeserializeOwned>(
+    url: &str,
+    http_client: &HttpClient,
+) -> Result<T> {
+    let response = handle_response(http_client.get(url).send().await?)?;
+    Ok(response.json::<T>().await?)
 }
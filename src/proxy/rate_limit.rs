@@ -4,6 +4,9 @@ use std::{
     time::Duration,
 };
 
+/// The shortest pause after being rate limited, for responses without a `Retry-After` header.
+pub const MIN_RETRY_AFTER: Duration = Duration::from_secs(1);
+
 #[derive(Debug, Default)]
 pub struct Rate {
     pub requests: Option<u16>,
@@ -0,0 +1,236 @@
+//! Contains the `Rank` value type for comparing and measuring ranked standings.
+
+use crate::{
+    enums::{Division, Tier},
+    types::{LeagueEntry, LeagueItem},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter, Result},
+    hash::{Hash, Hasher},
+};
+
+/// League points needed to move through a single division.
+const LEAGUE_POINTS_PER_DIVISION: i32 = 100;
+/// League points needed to move through a tier with divisions.
+const LEAGUE_POINTS_PER_TIER: i32 = 4 * LEAGUE_POINTS_PER_DIVISION;
+
+/// A ranked standing made of a tier, division and league points.
+///
+/// Ranks are ordered by their `ladder_score`, so master, grandmaster and challenger ranks are ordered by league points
+/// first, with the higher tier winning ties. Apex tiers have no divisions, their division is always `Division::One`.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::{Division, Tier},
+/// #    rank::Rank,
+/// # };
+/// let diamond = Rank::new(Tier::Diamond, Division::Two, 45);
+/// let master = Rank::new(Tier::Master, Division::One, 120);
+///
+/// assert!(diamond < master);
+/// assert_eq!(diamond.to_string(), "Diamond II 45 LP");
+/// assert_eq!(master.to_string(), "Master 120 LP");
+/// assert_eq!(diamond.lp_to_next_division(), Some(55));
+/// assert_eq!(diamond.lp_distance(&master), 55 + 100 + 120);
+///
+/// assert!(Rank::new(Tier::Master, Division::One, 500) > Rank::new(Tier::Grandmaster, Division::One, 100));
+/// assert_eq!(
+///     Rank::new(Tier::Master, Division::Four, 300),
+///     Rank::new(Tier::Master, Division::One, 300),
+/// );
+///
+/// // Tiers added after this version of the crate are displayed as received and ranked below Iron IV.
+/// let emerald = Rank::new(Tier::Unknown("EMERALD".to_string()), Division::Two, 50);
+///
+/// assert_eq!(emerald.to_string(), "EMERALD II 50 LP");
+/// assert!(emerald < Rank::new(Tier::Iron, Division::Four, 0));
+/// assert_ne!(emerald, Rank::new(Tier::Unknown("JADE".to_string()), Division::Two, 50));
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Rank {
+    pub tier: Tier,
+    pub division: Division,
+    pub league_points: i32,
+}
+
+impl Rank {
+    /// Constructs an instance of `Rank`, ignoring the division of apex tiers.
+    pub fn new(tier: Tier, division: Division, league_points: i32) -> Self {
+        let division = if tier.is_apex() {
+            Division::One
+        } else {
+            division
+        };
+
+        Rank {
+            tier,
+            division,
+            league_points,
+        }
+    }
+
+    /// Constructs the `Rank` of a `LeagueItem`, which does not carry its tier, from the tier of its `LeagueList`.
+    pub fn from_league_item(tier: Tier, league_item: &LeagueItem) -> Self {
+        Rank::new(tier, league_item.rank.clone(), league_item.league_points)
+    }
+
+    /// A linear score where each division is worth 100 LP, starting at 0 for Iron IV 0 LP.
+    ///
+    /// Master, grandmaster and challenger share a single ladder starting at Master 0 LP, as they have no divisions.
+    /// Tiers and divisions unknown to this crate have no known place on the ladder and score below Iron IV.
+    pub fn ladder_score(&self) -> i32 {
+        let tier_index = self.tier_index().min(6);
+        let division_index = self.division_index();
+
+        if tier_index < 0 || division_index < 0 {
+            return self.league_points - LEAGUE_POINTS_PER_TIER;
+        }
+
+        if self.tier.is_apex() {
+            return tier_index * LEAGUE_POINTS_PER_TIER + self.league_points;
+        }
+
+        tier_index * LEAGUE_POINTS_PER_TIER
+            + division_index * LEAGUE_POINTS_PER_DIVISION
+            + self.league_points
+    }
+
+    /// League points needed to reach the next division, or `None` for apex tiers which have no divisions.
+    pub fn lp_to_next_division(&self) -> Option<i32> {
+        if self.tier.is_apex() {
+            None
+        } else {
+            Some((LEAGUE_POINTS_PER_DIVISION - self.league_points).max(0))
+        }
+    }
+
+    /// League points between this rank and `other`, positive when `other` is higher.
+    pub fn lp_distance(&self, other: &Rank) -> i32 {
+        other.ladder_score() - self.ladder_score()
+    }
+
+    /// The position of the tier from 0 for iron to 8 for challenger, -1 for unknown tiers.
+    fn tier_index(&self) -> i32 {
+        match self.tier {
+            Tier::Unknown(_) => -1,
+            Tier::Iron => 0,
+            Tier::Bronze => 1,
+            Tier::Silver => 2,
+            Tier::Gold => 3,
+            Tier::Platinum => 4,
+            Tier::Diamond => 5,
+            Tier::Master => 6,
+            Tier::Grandmaster => 7,
+            Tier::Challenger => 8,
+        }
+    }
+
+    /// The position of the division from 0 for division IV to 3 for division I, always 0 for apex tiers and -1 for
+    /// unknown divisions.
+    fn division_index(&self) -> i32 {
+        if self.tier.is_apex() {
+            return 0;
+        }
+
+        match self.division {
+            Division::Unknown(_) => -1,
+            Division::Four => 0,
+            Division::Three => 1,
+            Division::Two => 2,
+            Division::One => 3,
+        }
+    }
+
+    /// The tier and division on the ladder, ignoring league points. Unknown tiers are below Iron, like their
+    /// `ladder_score`.
+    pub(crate) fn division_key(&self) -> (i32, i32) {
+        (self.tier_index(), self.division_index())
+    }
+
+    /// The tier and division as received when unknown to this crate, so different unknown values are not equal.
+    fn unknown_names(&self) -> (&str, &str) {
+        let tier = match &self.tier {
+            Tier::Unknown(tier) => tier.as_str(),
+            _ => "",
+        };
+        let division = match &self.division {
+            Division::Unknown(division) if !self.tier.is_apex() => division.as_str(),
+            _ => "",
+        };
+
+        (tier, division)
+    }
+
+    /// The fields compared by `Ord`, `Eq` and `Hash`, agreeing with `ladder_score`.
+    fn key(&self) -> (i32, i32, i32, i32, (&str, &str)) {
+        (
+            self.ladder_score(),
+            self.tier_index(),
+            self.division_index(),
+            self.league_points,
+            self.unknown_names(),
+        )
+    }
+}
+
+impl PartialEq for Rank {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Rank {}
+
+impl PartialOrd for Rank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl Hash for Rank {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl From<&LeagueEntry> for Rank {
+    fn from(league_entry: &LeagueEntry) -> Self {
+        Rank::new(
+            league_entry.tier.clone(),
+            league_entry.rank.clone(),
+            league_entry.league_points,
+        )
+    }
+}
+
+impl Display for Rank {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let tier = match &self.tier {
+            Tier::Unknown(tier) => tier.as_str(),
+            Tier::Iron => "Iron",
+            Tier::Bronze => "Bronze",
+            Tier::Silver => "Silver",
+            Tier::Gold => "Gold",
+            Tier::Platinum => "Platinum",
+            Tier::Diamond => "Diamond",
+            Tier::Master => "Master",
+            Tier::Grandmaster => "Grandmaster",
+            Tier::Challenger => "Challenger",
+        };
+
+        if self.tier.is_apex() {
+            write!(f, "{} {} LP", tier, self.league_points)
+        } else {
+            write!(f, "{} {} {} LP", tier, self.division, self.league_points)
+        }
+    }
+}
@@ -0,0 +1,212 @@
+#[cfg(feature = "snapshot")]
+mod snapshot;
+
+use crate::{
+    enums::QueueId,
+    types::{
+        StaticChampion, StaticItem, StaticMap, StaticQueue, StaticRune, StaticRuneStyle,
+        StaticSummonerSpell,
+    },
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The `{ "version": ..., "data": { ... } }` wrapper around Data Dragon lists.
+#[derive(Deserialize)]
+pub(crate) struct DataDragonList<T> {
+    pub(crate) data: HashMap<String, T>,
+}
+
+impl<T> DataDragonList<T> {
+    pub(crate) fn into_vec(self) -> Vec<T> {
+        self.data.into_values().collect()
+    }
+}
+
+impl DataDragonList<StaticItem> {
+    /// Items are keyed by their id rather than carrying it, so copy the key onto each item.
+    pub(crate) fn into_items(self) -> Vec<StaticItem> {
+        self.data
+            .into_iter()
+            .filter_map(|(item_id, mut item)| {
+                item.id = item_id.parse().ok()?;
+                Some(item)
+            })
+            .collect()
+    }
+}
+
+/// Static data for a single game version and locale, used to resolve the bare ids found in the match types.
+///
+/// Use `DataDragon::get_static_data` to load an instance, or with the `snapshot` feature enabled
+/// `StaticData::bundled()` and `StaticData::from_directory(...)` to load it without network access.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{DataDragon, Error};
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let data_dragon = DataDragon::new();
+///
+/// if let Ok(static_data) = data_dragon.get_static_data("11.1.1", "en_US").await {
+///     // Resolve a `Participant::champion_id`.
+///     if let Some(champion) = static_data.champion(62) {
+///         println!("{}", champion.name);
+///     }
+/// }
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct StaticData {
+    version: String,
+    locale: String,
+    champions: HashMap<i32, StaticChampion>,
+    items: HashMap<i32, StaticItem>,
+    rune_styles: Vec<StaticRuneStyle>,
+    runes: HashMap<i32, StaticRune>,
+    summoner_spells: HashMap<i32, StaticSummonerSpell>,
+    queues: HashMap<i32, StaticQueue>,
+    maps: HashMap<i32, StaticMap>,
+}
+
+impl StaticData {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        version: String,
+        locale: String,
+        champions: Vec<StaticChampion>,
+        items: Vec<StaticItem>,
+        rune_styles: Vec<StaticRuneStyle>,
+        summoner_spells: Vec<StaticSummonerSpell>,
+        queues: Vec<StaticQueue>,
+        maps: Vec<StaticMap>,
+    ) -> Self {
+        let runes = rune_styles
+            .iter()
+            .flat_map(|rune_style| rune_style.slots.iter())
+            .flat_map(|rune_slot| rune_slot.runes.iter())
+            .map(|rune| (rune.id, rune.clone()))
+            .collect();
+
+        StaticData {
+            version,
+            locale,
+            champions: champions
+                .into_iter()
+                .filter_map(|champion| Some((champion.key.parse().ok()?, champion)))
+                .collect(),
+            items: items.into_iter().map(|item| (item.id, item)).collect(),
+            rune_styles,
+            runes,
+            summoner_spells: summoner_spells
+                .into_iter()
+                .filter_map(|summoner_spell| {
+                    Some((summoner_spell.key.parse().ok()?, summoner_spell))
+                })
+                .collect(),
+            queues: queues
+                .into_iter()
+                .map(|queue| (queue.queue_id, queue))
+                .collect(),
+            maps: maps.into_iter().map(|map| (map.map_id, map)).collect(),
+        }
+    }
+
+    /// The game version this data was loaded for, e.g. `11.1.1`.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// The locale this data was loaded for, e.g. `en_US`.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Get a champion by its numeric ID, as found in `Participant::champion_id`.
+    pub fn champion(&self, champion_id: i32) -> Option<&StaticChampion> {
+        self.champions.get(&champion_id)
+    }
+
+    /// Get a champion by its key, e.g. `MonkeyKing`.
+    pub fn champion_by_key(&self, key: &str) -> Option<&StaticChampion> {
+        self.champions.values().find(|champion| champion.id == key)
+    }
+
+    /// All champions.
+    pub fn champions(&self) -> impl Iterator<Item = &StaticChampion> {
+        self.champions.values()
+    }
+
+    /// Get an item by its numeric ID, as found in `ParticipantStats::item_0` through `item_6`.
+    pub fn item(&self, item_id: i32) -> Option<&StaticItem> {
+        self.items.get(&item_id)
+    }
+
+    /// All items.
+    pub fn items(&self) -> impl Iterator<Item = &StaticItem> {
+        self.items.values()
+    }
+
+    /// Get a rune by its numeric ID, as found in `ParticipantStats::perk_0` through `perk_5`.
+    pub fn rune(&self, rune_id: i32) -> Option<&StaticRune> {
+        self.runes.get(&rune_id)
+    }
+
+    /// Get a rune by its key, e.g. `Electrocute`.
+    pub fn rune_by_key(&self, key: &str) -> Option<&StaticRune> {
+        self.runes.values().find(|rune| rune.key == key)
+    }
+
+    /// Get a rune style by its numeric ID, as found in `ParticipantStats::perk_primary_style` and `perk_sub_style`.
+    pub fn rune_style(&self, rune_style_id: i32) -> Option<&StaticRuneStyle> {
+        self.rune_styles
+            .iter()
+            .find(|rune_style| rune_style.id == rune_style_id)
+    }
+
+    /// All rune styles, each containing their runes.
+    pub fn rune_styles(&self) -> impl Iterator<Item = &StaticRuneStyle> {
+        self.rune_styles.iter()
+    }
+
+    /// Get a summoner spell by its numeric ID, as found in `Participant::spell_1_id` and `spell_2_id`.
+    pub fn summoner_spell(&self, summoner_spell_id: i32) -> Option<&StaticSummonerSpell> {
+        self.summoner_spells.get(&summoner_spell_id)
+    }
+
+    /// Get a summoner spell by its key, e.g. `SummonerFlash`.
+    pub fn summoner_spell_by_key(&self, key: &str) -> Option<&StaticSummonerSpell> {
+        self.summoner_spells
+            .values()
+            .find(|summoner_spell| summoner_spell.id == key)
+    }
+
+    /// All summoner spells.
+    pub fn summoner_spells(&self) -> impl Iterator<Item = &StaticSummonerSpell> {
+        self.summoner_spells.values()
+    }
+
+    /// Get a queue by its ID, as found in `Match::queue_id`.
+    pub fn queue(&self, queue_id: QueueId) -> Option<&StaticQueue> {
+        self.queues.get(&queue_id.id())
+    }
+
+    /// All queues.
+    pub fn queues(&self) -> impl Iterator<Item = &StaticQueue> {
+        self.queues.values()
+    }
+
+    /// Get a map by its numeric ID, as found in `Match::map_id`.
+    pub fn map(&self, map_id: i32) -> Option<&StaticMap> {
+        self.maps.get(&map_id)
+    }
+
+    /// All maps.
+    pub fn maps(&self) -> impl Iterator<Item = &StaticMap> {
+        self.maps.values()
+    }
+}
@@ -0,0 +1,103 @@
+use super::{DataDragonList, StaticData};
+use crate::{proxy::Result, types::StaticItem};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::{fs, path::Path};
+
+const CHAMPION_FILE: &str = "champion.json";
+const ITEM_FILE: &str = "item.json";
+const MANIFEST_FILE: &str = "manifest.json";
+const MAP_FILE: &str = "maps.json";
+const QUEUE_FILE: &str = "queues.json";
+const RUNE_FILE: &str = "runesReforged.json";
+const SUMMONER_SPELL_FILE: &str = "summoner.json";
+
+/// The version and locale a snapshot was taken from.
+#[derive(Deserialize)]
+struct Manifest {
+    version: String,
+    locale: String,
+}
+
+/// The raw contents of each file in a snapshot.
+struct SnapshotFiles<T: AsRef<[u8]>> {
+    manifest: T,
+    champions: T,
+    items: T,
+    runes: T,
+    summoner_spells: T,
+    queues: T,
+    maps: T,
+}
+
+fn parse<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    Ok(serde_json::from_slice::<T>(bytes)?)
+}
+
+fn from_files<T: AsRef<[u8]>>(files: SnapshotFiles<T>) -> Result<StaticData> {
+    let manifest = parse::<Manifest>(files.manifest.as_ref())?;
+
+    Ok(StaticData::new(
+        manifest.version,
+        manifest.locale,
+        parse::<DataDragonList<_>>(files.champions.as_ref())?.into_vec(),
+        parse::<DataDragonList<StaticItem>>(files.items.as_ref())?.into_items(),
+        parse(files.runes.as_ref())?,
+        parse::<DataDragonList<_>>(files.summoner_spells.as_ref())?.into_vec(),
+        parse(files.queues.as_ref())?,
+        parse(files.maps.as_ref())?,
+    ))
+}
+
+impl StaticData {
+    /// The static data snapshot embedded in this crate.
+    ///
+    /// Only the ids, keys and names are included, which is enough to resolve the ids found in the match types.
+    /// Use `StaticData::from_directory(...)` to load a newer snapshot.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{enums::QueueId, StaticData};
+    /// let static_data = StaticData::bundled();
+    ///
+    /// assert_eq!(static_data.champion(62).unwrap().name, "Wukong");
+    /// assert_eq!(static_data.summoner_spell_by_key("SummonerFlash").unwrap().name, "Flash");
+    /// assert_eq!(static_data.queue(QueueId::RankedSolo).unwrap().map, "Summoner's Rift");
+    /// ```
+    pub fn bundled() -> Self {
+        from_files(SnapshotFiles {
+            manifest: include_str!("../../data/snapshot/manifest.json"),
+            champions: include_str!("../../data/snapshot/champion.json"),
+            items: include_str!("../../data/snapshot/item.json"),
+            runes: include_str!("../../data/snapshot/runesReforged.json"),
+            summoner_spells: include_str!("../../data/snapshot/summoner.json"),
+            queues: include_str!("../../data/snapshot/queues.json"),
+            maps: include_str!("../../data/snapshot/maps.json"),
+        })
+        .expect("Unable to parse the bundled static data snapshot.")
+    }
+
+    /// Load a snapshot from a local directory.
+    ///
+    /// The directory uses the same layout as the bundled snapshot: the Data Dragon `champion.json`, `item.json`,
+    /// `runesReforged.json` and `summoner.json` files, the developer portal `queues.json` and `maps.json` files,
+    /// and a `manifest.json` containing the `version` and `locale` of the snapshot.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::StaticData;
+    /// let static_data = StaticData::from_directory("/var/lib/static-data/11.2.1");
+    /// ```
+    pub fn from_directory<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        let directory = directory.as_ref();
+
+        from_files(SnapshotFiles {
+            manifest: fs::read(directory.join(MANIFEST_FILE))?,
+            champions: fs::read(directory.join(CHAMPION_FILE))?,
+            items: fs::read(directory.join(ITEM_FILE))?,
+            runes: fs::read(directory.join(RUNE_FILE))?,
+            summoner_spells: fs::read(directory.join(SUMMONER_SPELL_FILE))?,
+            queues: fs::read(directory.join(QUEUE_FILE))?,
+            maps: fs::read(directory.join(MAP_FILE))?,
+        })
+    }
+}
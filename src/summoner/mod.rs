@@ -1,7 +1,7 @@
 use crate::{
     enums::Region,
     proxy::{request, Result},
-    types::Summoner as SummonerDTO,
+    types::{AccountId, Puuid, Summoner as SummonerDTO, SummonerId},
     Client, ClientContext,
 };
 use url::Url;
@@ -21,7 +21,7 @@ impl Client {
     /// // Access the summoner API methods.
     /// let summoner_api = client.league();
     /// ```
-    pub fn summoner(&self) -> Summoner {
+    pub fn summoner(&self) -> Summoner<'_> {
         Summoner {
             context: &self.context,
         }
@@ -39,6 +39,7 @@ impl Client {
 /// ```rust
 /// # use league_of_legends_client::{
 /// #    enums::{Queue, Region},
+/// #    types::AccountId,
 /// #    Client, ClientOptions,
 /// #    Error,
 /// # };
@@ -52,7 +53,7 @@ impl Client {
 ///     .summoner()
 ///     .get_summoner_by_encrypted_account_id(
 ///         Region::NorthAmerica,
-///         "EXAMPLE_ENCRYPTED_ACCOUNT_ID".to_string()
+///         AccountId::from("EXAMPLE_ENCRYPTED_ACCOUNT_ID")
 ///     )
 ///     .await;
 ///
@@ -62,7 +63,7 @@ impl Client {
 /// summoner_one = summoner_api
 ///     .get_summoner_by_encrypted_account_id(
 ///         Region::NorthAmerica,
-///         "EXAMPLE_ENCRYPTED_ACCOUNT_ID".to_string()
+///         AccountId::from("EXAMPLE_ENCRYPTED_ACCOUNT_ID")
 ///     )
 ///     .await;
 /// let summoner_two = summoner_api
@@ -89,6 +90,7 @@ impl Summoner<'_> {
     /// ```rust
     /// # use league_of_legends_client::{
     /// #    enums::Region,
+    /// #    types::AccountId,
     /// #    Client, ClientOptions,
     /// #    Error,
     /// # };
@@ -100,7 +102,7 @@ impl Summoner<'_> {
     ///     .summoner()
     ///     .get_summoner_by_encrypted_account_id(
     ///         Region::NorthAmerica,
-    ///         "EXAMPLE_ENCRYPTED_ACCOUNT_ID".to_string()
+    ///         AccountId::from("EXAMPLE_ENCRYPTED_ACCOUNT_ID")
     ///     )
     ///     .await;
     /// #
@@ -110,12 +112,11 @@ impl Summoner<'_> {
     pub async fn get_summoner_by_encrypted_account_id(
         &self,
         region: Region,
-        encrypted_account_id: String,
+        encrypted_account_id: AccountId,
     ) -> Result<SummonerDTO> {
         let url = Url::parse(&format!(
             "https://{}/lol/summoner/v4/summoners/by-account/{}",
-            region.to_string(),
-            encrypted_account_id
+            region, encrypted_account_id
         ))?;
 
         request::<SummonerDTO>(url.as_str(), self.context).await
@@ -154,8 +155,7 @@ impl Summoner<'_> {
     ) -> Result<SummonerDTO> {
         let url = Url::parse(&format!(
             "https://{}/lol/summoner/v4/summoners/by-name/{}",
-            region.to_string(),
-            summoner_name
+            region, summoner_name
         ))?;
 
         request::<SummonerDTO>(url.as_str(), self.context).await
@@ -169,6 +169,7 @@ impl Summoner<'_> {
     /// ```rust
     /// # use league_of_legends_client::{
     /// #    enums::Region,
+    /// #    types::Puuid,
     /// #    Client, ClientOptions,
     /// #    Error,
     /// # };
@@ -180,7 +181,7 @@ impl Summoner<'_> {
     ///     .summoner()
     ///     .get_summoner_by_encrypted_puuid(
     ///         Region::NorthAmerica,
-    ///         "EXAMPLE_PUUID".to_string()
+    ///         Puuid::from("EXAMPLE_PUUID")
     ///     )
     ///     .await;
     /// #
@@ -190,12 +191,11 @@ impl Summoner<'_> {
     pub async fn get_summoner_by_encrypted_puuid(
         &self,
         region: Region,
-        encrypted_puu_id: String,
+        encrypted_puu_id: Puuid,
     ) -> Result<SummonerDTO> {
         let url = Url::parse(&format!(
             "https://{}/lol/summoner/v4/summoners/by-puuid/{}",
-            region.to_string(),
-            encrypted_puu_id
+            region, encrypted_puu_id
         ))?;
 
         request::<SummonerDTO>(url.as_str(), self.context).await
@@ -211,6 +211,7 @@ impl Summoner<'_> {
     /// ```rust
     /// # use league_of_legends_client::{
     /// #    enums::Region,
+    /// #    types::SummonerId,
     /// #    Client, ClientOptions,
     /// #    Error,
     /// # };
@@ -222,7 +223,7 @@ impl Summoner<'_> {
     ///     .summoner()
     ///     .get_summoner_by_encrypted_summoner_id(
     ///         Region::NorthAmerica,
-    ///         "EXAMPLE_ENCRYPTED_SUMMONER_ID".to_string()
+    ///         SummonerId::from("EXAMPLE_ENCRYPTED_SUMMONER_ID")
     ///     )
     ///     .await;
     /// #
@@ -232,12 +233,11 @@ impl Summoner<'_> {
     pub async fn get_summoner_by_encrypted_summoner_id(
         &self,
         region: Region,
-        encrypted_summoner_id: String,
+        encrypted_summoner_id: SummonerId,
     ) -> Result<SummonerDTO> {
         let url = Url::parse(&format!(
             "https://{}/lol/summoner/v4/summoners/{}",
-            region.to_string(),
-            encrypted_summoner_id
+            region, encrypted_summoner_id
         ))?;
 
         request::<SummonerDTO>(url.as_str(), self.context).await
@@ -0,0 +1,315 @@
+use crate::{
+    enums::{Division, Region, TftQueue, Tier},
+    proxy::{request, Result},
+    types::{LeagueList, SummonerId, TftLeagueEntry, TopRatedLadderEntry},
+    Client, ClientContext,
+};
+use url::Url;
+
+impl Client {
+    /// Access the teamfight tactics league namespace that contains league related API methods.
+    ///
+    /// [Riot API documentation](https://developer.riotgames.com/apis#tft-league-v1).
+    ///
+    /// Use this method instead of manually constructing a `TftLeague` struct.
+    /// # Example
+    ///
+    /// ```rust
+    /// # use league_of_legends_client::{Client, ClientOptions};
+    /// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    ///
+    /// // Access the teamfight tactics league API methods.
+    /// let tft_league_api = client.tft_league();
+    /// ```
+    pub fn tft_league(&self) -> TftLeague<'_> {
+        TftLeague {
+            context: &self.context,
+        }
+    }
+}
+
+/// Namespace for the teamfight tactics league related APIs.
+///
+/// [Riot API documentation](https://developer.riotgames.com/apis#tft-league-v1).
+///
+/// Do not create this struct manually. The preferred method is to call `client.tft_league()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::{Region, TftQueue},
+/// #    Client, ClientOptions,
+/// #    Error,
+/// # };
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+///
+/// // Access the teamfight tactics league API methods.
+/// let mut challenger_league = client
+///     .tft_league()
+///     .get_challenger_league(Region::NorthAmerica)
+///     .await;
+///
+/// // Alternatively use this to save some characters.
+/// let tft_league_api = client.tft_league();
+///
+/// challenger_league = tft_league_api
+///     .get_challenger_league(Region::NorthAmerica)
+///     .await;
+/// let top_rated_ladder = tft_league_api
+///     .get_top_rated_ladder(Region::NorthAmerica, TftQueue::RankedTftTurbo)
+///     .await;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TftLeague<'a> {
+    context: &'a ClientContext,
+}
+
+impl TftLeague<'_> {
+    /// Get the challenger league.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tft-league-v1/GET_getChallengerLeague).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let challenger_league = client
+    ///     .tft_league()
+    ///     .get_challenger_league(Region::NorthAmerica)
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_challenger_league(&self, region: Region) -> Result<LeagueList> {
+        let url = Url::parse(&format!("https://{}/tft/league/v1/challenger", region))?;
+
+        request::<LeagueList>(url.as_str(), self.context).await
+    }
+
+    /// Get the grandmaster league.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tft-league-v1/GET_getGrandmasterLeague).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let grandmaster_league = client
+    ///     .tft_league()
+    ///     .get_grandmaster_league(Region::NorthAmerica)
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_grandmaster_league(&self, region: Region) -> Result<LeagueList> {
+        let url = Url::parse(&format!("https://{}/tft/league/v1/grandmaster", region))?;
+
+        request::<LeagueList>(url.as_str(), self.context).await
+    }
+
+    /// Get the master league.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tft-league-v1/GET_getMasterLeague).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let master_league = client
+    ///     .tft_league()
+    ///     .get_master_league(Region::NorthAmerica)
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_master_league(&self, region: Region) -> Result<LeagueList> {
+        let url = Url::parse(&format!("https://{}/tft/league/v1/master", region))?;
+
+        request::<LeagueList>(url.as_str(), self.context).await
+    }
+
+    /// Get league entries for a given summoner ID.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tft-league-v1/GET_getLeagueEntriesForSummoner).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    types::SummonerId,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let league_entries = client
+    ///     .tft_league()
+    ///     .get_league_entries_for_summoner(
+    ///         Region::NorthAmerica,
+    ///         SummonerId::from("EXAMPLE_ENCRYPTED_SUMMONER_ID"),
+    ///     )
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_league_entries_for_summoner(
+        &self,
+        region: Region,
+        encrypted_summoner_id: SummonerId,
+    ) -> Result<Vec<TftLeagueEntry>> {
+        let url = Url::parse(&format!(
+            "https://{}/tft/league/v1/entries/by-summoner/{}",
+            region, encrypted_summoner_id
+        ))?;
+
+        request::<Vec<TftLeagueEntry>>(url.as_str(), self.context).await
+    }
+
+    /// Get all the league entries.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tft-league-v1/GET_getLeagueEntries).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::{Division, Region, Tier},
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let league_entries = client
+    ///     .tft_league()
+    ///     .get_league_entries(Region::NorthAmerica, Tier::Diamond, Division::One, None)
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_league_entries(
+        &self,
+        region: Region,
+        tier: Tier,
+        division: Division,
+        page: Option<i32>,
+    ) -> Result<Vec<TftLeagueEntry>> {
+        let mut url = Url::parse(&format!(
+            "https://{}/tft/league/v1/entries/{}/{}",
+            region, tier, division
+        ))?;
+
+        if let Some(page) = page {
+            url.query_pairs_mut().append_pair("page", &page.to_string());
+        }
+
+        request::<Vec<TftLeagueEntry>>(url.as_str(), self.context).await
+    }
+
+    /// Get league with given ID, including inactive entries.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tft-league-v1/GET_getLeagueById).
+    ///
+    /// **Consistently looking up league ids that don't exist will result in a blacklist.**
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let league = client
+    ///     .tft_league()
+    ///     .get_league(Region::NorthAmerica, "EXAMPLE_LEAGUE_ID".to_string())
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_league(&self, region: Region, league_id: String) -> Result<LeagueList> {
+        let url = Url::parse(&format!(
+            "https://{}/tft/league/v1/leagues/{}",
+            region, league_id
+        ))?;
+
+        request::<LeagueList>(url.as_str(), self.context).await
+    }
+
+    /// Get the top rated ladder for given queue.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tft-league-v1/GET_getTopRatedLadder).
+    ///
+    /// Rated ladders are only available for Hyper Roll, `TftQueue::RankedTftTurbo`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::{Region, TftQueue},
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let top_rated_ladder = client
+    ///     .tft_league()
+    ///     .get_top_rated_ladder(Region::NorthAmerica, TftQueue::RankedTftTurbo)
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_top_rated_ladder(
+        &self,
+        region: Region,
+        queue: TftQueue,
+    ) -> Result<Vec<TopRatedLadderEntry>> {
+        let url = Url::parse(&format!(
+            "https://{}/tft/league/v1/rated-ladders/{}/top",
+            region, queue
+        ))?;
+
+        request::<Vec<TopRatedLadderEntry>>(url.as_str(), self.context).await
+    }
+}
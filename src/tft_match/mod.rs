@@ -0,0 +1,175 @@
+use crate::{
+    enums::RegionalRoute,
+    proxy::{request, Result},
+    types::{MatchId, Puuid, TftMatch as TftMatchDTO},
+    Client, ClientContext,
+};
+use url::Url;
+
+impl Client {
+    /// Access the teamfight tactics match namespace that contains match related API methods.
+    ///
+    /// [Riot API documentation](https://developer.riotgames.com/apis#tft-match-v1).
+    ///
+    /// Use this method instead of manually constructing a `TftMatch` struct.
+    /// # Example
+    ///
+    /// ```rust
+    /// # use league_of_legends_client::{Client, ClientOptions};
+    /// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    ///
+    /// // Access the teamfight tactics match API methods.
+    /// let tft_match_api = client.tft_match();
+    /// ```
+    pub fn tft_match(&self) -> TftMatch<'_> {
+        TftMatch {
+            context: &self.context,
+        }
+    }
+}
+
+/// Namespace for the teamfight tactics match related APIs.
+///
+/// [Riot API documentation](https://developer.riotgames.com/apis#tft-match-v1).
+///
+/// Matches are served by regional routing values, `Region::regional_route()` can be used to find the route for a
+/// summoner's region.
+///
+/// Do not create this struct manually. The preferred method is to call `client.tft_match()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::{Region, RegionalRoute},
+/// #    types::MatchId,
+/// #    types::Puuid,
+/// #    Client, ClientOptions,
+/// #    Error,
+/// # };
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+///
+/// // Access the teamfight tactics match API methods.
+/// let mut game = client
+///     .tft_match()
+///     .get_match_by_id(RegionalRoute::Americas, MatchId::from("NA1_00000"))
+///     .await;
+///
+/// // Alternatively use this to save some characters.
+/// let tft_match_api = client.tft_match();
+///
+/// game = tft_match_api
+///     .get_match_by_id(Region::NorthAmerica.regional_route(), MatchId::from("NA1_00000"))
+///     .await;
+/// let match_ids = tft_match_api
+///     .get_match_ids_by_puuid(
+///         RegionalRoute::Americas,
+///         Puuid::from("EXAMPLE_PUUID"),
+///         None,
+///         Some(20),
+///     )
+///     .await;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TftMatch<'a> {
+    context: &'a ClientContext,
+}
+
+impl TftMatch<'_> {
+    /// Get a list of match ids by PUUID.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tft-match-v1/GET_getMatchIdsByPUUID).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::RegionalRoute,
+    /// #    types::Puuid,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let match_ids = client
+    ///     .tft_match()
+    ///     .get_match_ids_by_puuid(
+    ///         RegionalRoute::Americas,
+    ///         Puuid::from("EXAMPLE_PUUID"),
+    ///         None,
+    ///         Some(20),
+    ///     )
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_match_ids_by_puuid(
+        &self,
+        regional_route: RegionalRoute,
+        puuid: Puuid,
+        start: Option<i32>,
+        count: Option<i32>,
+    ) -> Result<Vec<MatchId>> {
+        let mut url = Url::parse(&format!(
+            "https://{}/tft/match/v1/matches/by-puuid/{}/ids",
+            regional_route, puuid
+        ))?;
+
+        if let Some(start) = start {
+            url.query_pairs_mut()
+                .append_pair("start", &start.to_string());
+        }
+
+        if let Some(count) = count {
+            url.query_pairs_mut()
+                .append_pair("count", &count.to_string());
+        }
+
+        request::<Vec<MatchId>>(url.as_str(), self.context).await
+    }
+
+    /// Get a match by match id.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tft-match-v1/GET_getMatch).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::RegionalRoute,
+    /// #    types::MatchId,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let game = client
+    ///     .tft_match()
+    ///     .get_match_by_id(RegionalRoute::Americas, MatchId::from("NA1_00000"))
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_match_by_id(
+        &self,
+        regional_route: RegionalRoute,
+        match_id: MatchId,
+    ) -> Result<TftMatchDTO> {
+        let url = Url::parse(&format!(
+            "https://{}/tft/match/v1/matches/{}",
+            regional_route, match_id
+        ))?;
+
+        request::<TftMatchDTO>(url.as_str(), self.context).await
+    }
+}
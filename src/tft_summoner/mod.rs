@@ -0,0 +1,245 @@
+use crate::{
+    enums::Region,
+    proxy::{request, Result},
+    types::{AccountId, Puuid, Summoner as SummonerDTO, SummonerId},
+    Client, ClientContext,
+};
+use url::Url;
+
+impl Client {
+    /// Access the teamfight tactics summoner namespace that contains summoner related API methods.
+    ///
+    /// [Riot API documentation](https://developer.riotgames.com/apis#tft-summoner-v1).
+    ///
+    /// Use this method instead of manually constructing a `TftSummoner` struct.
+    /// # Example
+    ///
+    /// ```rust
+    /// # use league_of_legends_client::{Client, ClientOptions};
+    /// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    ///
+    /// // Access the teamfight tactics summoner API methods.
+    /// let tft_summoner_api = client.tft_summoner();
+    /// ```
+    pub fn tft_summoner(&self) -> TftSummoner<'_> {
+        TftSummoner {
+            context: &self.context,
+        }
+    }
+}
+
+/// Namespace for the teamfight tactics summoner related APIs.
+///
+/// [Riot API documentation](https://developer.riotgames.com/apis#tft-summoner-v1).
+///
+/// Do not create this struct manually. The preferred method is to call `client.tft_summoner()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::{Queue, Region},
+/// #    types::AccountId,
+/// #    Client, ClientOptions,
+/// #    Error,
+/// # };
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+///
+/// // Access the teamfight tactics summoner API methods.
+/// let mut summoner_one = client
+///     .tft_summoner()
+///     .get_summoner_by_encrypted_account_id(
+///         Region::NorthAmerica,
+///         AccountId::from("EXAMPLE_ENCRYPTED_ACCOUNT_ID")
+///     )
+///     .await;
+///
+/// // Alternatively use this to save some characters.
+/// let tft_summoner_api = client.tft_summoner();
+///
+/// summoner_one = tft_summoner_api
+///     .get_summoner_by_encrypted_account_id(
+///         Region::NorthAmerica,
+///         AccountId::from("EXAMPLE_ENCRYPTED_ACCOUNT_ID")
+///     )
+///     .await;
+/// let summoner_two = tft_summoner_api
+///     .get_summoner_by_summoner_name(
+///         Region::NorthAmerica,
+///         "EXAMPLE_NAME".to_string()
+///     )
+///     .await;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TftSummoner<'a> {
+    context: &'a ClientContext,
+}
+
+impl TftSummoner<'_> {
+    /// Get a summoner by account ID.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tft-summoner-v1/GET_getByAccountId).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    types::AccountId,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let summoner = client
+    ///     .tft_summoner()
+    ///     .get_summoner_by_encrypted_account_id(
+    ///         Region::NorthAmerica,
+    ///         AccountId::from("EXAMPLE_ENCRYPTED_ACCOUNT_ID")
+    ///     )
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_summoner_by_encrypted_account_id(
+        &self,
+        region: Region,
+        encrypted_account_id: AccountId,
+    ) -> Result<SummonerDTO> {
+        let url = Url::parse(&format!(
+            "https://{}/tft/summoner/v1/summoners/by-account/{}",
+            region, encrypted_account_id
+        ))?;
+
+        request::<SummonerDTO>(url.as_str(), self.context).await
+    }
+
+    /// Get a summoner by summoner name.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tft-summoner-v1/GET_getBySummonerName).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let summoner = client
+    ///     .tft_summoner()
+    ///     .get_summoner_by_summoner_name(
+    ///         Region::NorthAmerica,
+    ///         "EXAMPLE_SUMMONER_NAME".to_string()
+    ///     )
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_summoner_by_summoner_name(
+        &self,
+        region: Region,
+        summoner_name: String,
+    ) -> Result<SummonerDTO> {
+        let url = Url::parse(&format!(
+            "https://{}/tft/summoner/v1/summoners/by-name/{}",
+            region, summoner_name
+        ))?;
+
+        request::<SummonerDTO>(url.as_str(), self.context).await
+    }
+
+    /// Get a summoner by PUUID.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tft-summoner-v1/GET_getByPUUID).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    types::Puuid,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let summoner = client
+    ///     .tft_summoner()
+    ///     .get_summoner_by_encrypted_puuid(
+    ///         Region::NorthAmerica,
+    ///         Puuid::from("EXAMPLE_PUUID")
+    ///     )
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_summoner_by_encrypted_puuid(
+        &self,
+        region: Region,
+        encrypted_puu_id: Puuid,
+    ) -> Result<SummonerDTO> {
+        let url = Url::parse(&format!(
+            "https://{}/tft/summoner/v1/summoners/by-puuid/{}",
+            region, encrypted_puu_id
+        ))?;
+
+        request::<SummonerDTO>(url.as_str(), self.context).await
+    }
+
+    /// Get a summoner by summoner ID.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tft-summoner-v1/GET_getBySummonerId).
+    ///
+    /// **Consistently looking up summoner ids that don't exist will result in a blacklist.**
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    types::SummonerId,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let summoner = client
+    ///     .tft_summoner()
+    ///     .get_summoner_by_encrypted_summoner_id(
+    ///         Region::NorthAmerica,
+    ///         SummonerId::from("EXAMPLE_ENCRYPTED_SUMMONER_ID")
+    ///     )
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_summoner_by_encrypted_summoner_id(
+        &self,
+        region: Region,
+        encrypted_summoner_id: SummonerId,
+    ) -> Result<SummonerDTO> {
+        let url = Url::parse(&format!(
+            "https://{}/tft/summoner/v1/summoners/{}",
+            region, encrypted_summoner_id
+        ))?;
+
+        request::<SummonerDTO>(url.as_str(), self.context).await
+    }
+}
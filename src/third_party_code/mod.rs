@@ -0,0 +1,163 @@
+use crate::{
+    enums::Region,
+    proxy::{request, Result},
+    types::SummonerId,
+    Client, ClientContext, Error,
+};
+use url::Url;
+
+impl Client {
+    /// Access the third party code namespace that contains third party code related API methods.
+    ///
+    /// [Riot API documentation](https://developer.riotgames.com/apis#third-party-code-v4).
+    ///
+    /// Use this method instead of manually constructing a `ThirdPartyCode` struct.
+    /// # Example
+    ///
+    /// ```rust
+    /// # use league_of_legends_client::{Client, ClientOptions};
+    /// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    ///
+    /// // Access the third party code API methods.
+    /// let third_party_code_api = client.third_party_code();
+    /// ```
+    pub fn third_party_code(&self) -> ThirdPartyCode<'_> {
+        ThirdPartyCode {
+            context: &self.context,
+        }
+    }
+}
+
+/// Namespace for the third party code related APIs.
+///
+/// [Riot API documentation](https://developer.riotgames.com/apis#third-party-code-v4).
+///
+/// Do not create this struct manually. The preferred method is to call `client.third_party_code()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::Region,
+/// #    types::SummonerId,
+/// #    Client, ClientOptions,
+/// #    Error,
+/// # };
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+///
+/// // Access the third party code API methods.
+/// let code = client
+///     .third_party_code()
+///     .get_third_party_code_by_summoner_id(
+///         Region::NorthAmerica,
+///         SummonerId::from("EXAMPLE_ENCRYPTED_SUMMONER_ID"),
+///     )
+///     .await;
+///
+/// // Alternatively use this to save some characters.
+/// let third_party_code_api = client.third_party_code();
+///
+/// let is_owner = third_party_code_api
+///     .verify_ownership(
+///         Region::NorthAmerica,
+///         SummonerId::from("EXAMPLE_ENCRYPTED_SUMMONER_ID"),
+///         "EXAMPLE_CODE",
+///     )
+///     .await;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ThirdPartyCode<'a> {
+    context: &'a ClientContext,
+}
+
+impl ThirdPartyCode<'_> {
+    /// Get third party code for a given summoner ID.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#third-party-code-v4/GET_getThirdPartyCodeBySummonerId).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    types::SummonerId,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let code = client
+    ///     .third_party_code()
+    ///     .get_third_party_code_by_summoner_id(
+    ///         Region::NorthAmerica,
+    ///         SummonerId::from("EXAMPLE_ENCRYPTED_SUMMONER_ID"),
+    ///     )
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_third_party_code_by_summoner_id(
+        &self,
+        region: Region,
+        encrypted_summoner_id: SummonerId,
+    ) -> Result<String> {
+        let url = Url::parse(&format!(
+            "https://{}/lol/platform/v4/third-party-code/by-summoner/{}",
+            region, encrypted_summoner_id
+        ))?;
+
+        request::<String>(url.as_str(), self.context).await
+    }
+
+    /// Check whether the summoner has set the expected third party code.
+    ///
+    /// A summoner that has not set a code (404) is reported as `false` rather than as an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::Region,
+    /// #    types::SummonerId,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let is_owner = client
+    ///     .third_party_code()
+    ///     .verify_ownership(
+    ///         Region::NorthAmerica,
+    ///         SummonerId::from("EXAMPLE_ENCRYPTED_SUMMONER_ID"),
+    ///         "EXAMPLE_CODE",
+    ///     )
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn verify_ownership(
+        &self,
+        region: Region,
+        encrypted_summoner_id: SummonerId,
+        expected_code: &str,
+    ) -> Result<bool> {
+        match self
+            .get_third_party_code_by_summoner_id(region, encrypted_summoner_id)
+            .await
+        {
+            Ok(code) => Ok(code == expected_code),
+            Err(Error::NotFound) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Converts milliseconds since the unix epoch, as used by the Riot API, into a `SystemTime`.
+pub(crate) fn from_epoch_millis(millis: i64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(millis.max(0) as u64)
+}
+
+/// Converts a `SystemTime` into milliseconds since the unix epoch, as expected by the Riot API.
+pub(crate) fn to_epoch_millis(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+}
@@ -0,0 +1,269 @@
+use crate::{
+    enums::RegionalRoute,
+    proxy::{request, request_with_body, Result},
+    types::{
+        LobbyEventWrapper, ProviderRegistrationParameters, TournamentCodeParameters,
+        TournamentRegistrationParameters,
+    },
+    Client, ClientContext,
+};
+use reqwest::Method;
+use url::Url;
+
+impl Client {
+    /// Access the tournament stub namespace that contains tournament stub related API methods.
+    ///
+    /// The stub API mirrors the tournament API without creating real tournament codes, use it to test your
+    /// integration before applying for a tournament API key.
+    ///
+    /// [Riot API documentation](https://developer.riotgames.com/apis#tournament-stub-v4).
+    ///
+    /// Use this method instead of manually constructing a `TournamentStub` struct.
+    /// # Example
+    ///
+    /// ```rust
+    /// # use league_of_legends_client::{Client, ClientOptions};
+    /// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    ///
+    /// // Access the tournament stub API methods.
+    /// let tournament_stub_api = client.tournament_stub();
+    /// ```
+    pub fn tournament_stub(&self) -> TournamentStub<'_> {
+        TournamentStub {
+            context: &self.context,
+        }
+    }
+}
+
+/// Namespace for the tournament stub related APIs.
+///
+/// [Riot API documentation](https://developer.riotgames.com/apis#tournament-stub-v4).
+///
+/// Do not create this struct manually. The preferred method is to call `client.tournament_stub()`.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    types::{ProviderRegistrationParameters, TournamentRegion, TournamentRegistrationParameters},
+/// #    Client, ClientOptions,
+/// #    Error,
+/// # };
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+///
+/// // Access the tournament stub API methods.
+/// let provider_id = client
+///     .tournament_stub()
+///     .register_provider(ProviderRegistrationParameters {
+///         region: TournamentRegion::Na,
+///         url: "https://example.com/callback".to_string(),
+///     })
+///     .await;
+///
+/// // Alternatively use this to save some characters.
+/// let tournament_stub_api = client.tournament_stub();
+///
+/// let tournament_id = tournament_stub_api
+///     .register_tournament(TournamentRegistrationParameters {
+///         provider_id: 0,
+///         name: Some("EXAMPLE_TOURNAMENT".to_string()),
+///     })
+///     .await;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TournamentStub<'a> {
+    context: &'a ClientContext,
+}
+
+impl TournamentStub<'_> {
+    /// Create a mock tournament code for the given tournament.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tournament-stub-v4/POST_createTournamentCode).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    types::{MapType, PickType, SpectatorType, TournamentCodeParameters},
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let tournament_codes = client
+    ///     .tournament_stub()
+    ///     .create_tournament_code(
+    ///         0,
+    ///         Some(5),
+    ///         TournamentCodeParameters {
+    ///             allowed_summoner_ids: None,
+    ///             metadata: None,
+    ///             team_size: 5,
+    ///             pick_type: PickType::TournamentDraft,
+    ///             map_type: MapType::SummonersRift,
+    ///             spectator_type: SpectatorType::All,
+    ///         },
+    ///     )
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_tournament_code(
+        &self,
+        tournament_id: i32,
+        count: Option<i32>,
+        parameters: TournamentCodeParameters,
+    ) -> Result<Vec<String>> {
+        let mut url = Url::parse(&format!(
+            "https://{}/lol/tournament-stub/v4/codes",
+            RegionalRoute::Americas,
+        ))?;
+
+        url.query_pairs_mut()
+            .append_pair("tournamentId", &tournament_id.to_string());
+
+        if let Some(count) = count {
+            url.query_pairs_mut()
+                .append_pair("count", &count.to_string());
+        }
+
+        request_with_body::<TournamentCodeParameters, Vec<String>>(
+            Method::POST,
+            url.as_str(),
+            &parameters,
+            self.context,
+        )
+        .await
+    }
+
+    /// Gets a mock list of lobby events by tournament code.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tournament-stub-v4/GET_getLobbyEventsByCode).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let lobby_events = client
+    ///     .tournament_stub()
+    ///     .get_lobby_events_by_code("EXAMPLE_TOURNAMENT_CODE".to_string())
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_lobby_events_by_code(
+        &self,
+        tournament_code: String,
+    ) -> Result<LobbyEventWrapper> {
+        let url = Url::parse(&format!(
+            "https://{}/lol/tournament-stub/v4/lobby-events/by-code/{}",
+            RegionalRoute::Americas,
+            tournament_code
+        ))?;
+
+        request::<LobbyEventWrapper>(url.as_str(), self.context).await
+    }
+
+    /// Creates a mock tournament provider and returns its ID.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tournament-stub-v4/POST_registerProviderData).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    types::{ProviderRegistrationParameters, TournamentRegion},
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let provider_id = client
+    ///     .tournament_stub()
+    ///     .register_provider(ProviderRegistrationParameters {
+    ///         region: TournamentRegion::Na,
+    ///         url: "https://example.com/callback".to_string(),
+    ///     })
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn register_provider(
+        &self,
+        parameters: ProviderRegistrationParameters,
+    ) -> Result<i32> {
+        let url = Url::parse(&format!(
+            "https://{}/lol/tournament-stub/v4/providers",
+            RegionalRoute::Americas,
+        ))?;
+
+        request_with_body::<ProviderRegistrationParameters, i32>(
+            Method::POST,
+            url.as_str(),
+            &parameters,
+            self.context,
+        )
+        .await
+    }
+
+    /// Creates a mock tournament and returns its ID.
+    ///
+    /// API documentation can be found [here](https://developer.riotgames.com/apis#tournament-stub-v4/POST_registerTournament).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    types::TournamentRegistrationParameters,
+    /// #    Client, ClientOptions,
+    /// #    Error,
+    /// # };
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let tournament_id = client
+    ///     .tournament_stub()
+    ///     .register_tournament(TournamentRegistrationParameters {
+    ///         provider_id: 0,
+    ///         name: Some("EXAMPLE_TOURNAMENT".to_string()),
+    ///     })
+    ///     .await;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn register_tournament(
+        &self,
+        parameters: TournamentRegistrationParameters,
+    ) -> Result<i32> {
+        let url = Url::parse(&format!(
+            "https://{}/lol/tournament-stub/v4/tournaments",
+            RegionalRoute::Americas,
+        ))?;
+
+        request_with_body::<TournamentRegistrationParameters, i32>(
+            Method::POST,
+            url.as_str(),
+            &parameters,
+            self.context,
+        )
+        .await
+    }
+}
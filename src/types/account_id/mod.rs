@@ -0,0 +1,4 @@
+string_id! {
+    /// An encrypted account id, unique per region.
+    AccountId
+}
@@ -0,0 +1,13 @@
+use super::Puuid;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApexPlayerInfo {
+    pub puuid: Puuid,
+    pub value: f64,
+    pub position: i32,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
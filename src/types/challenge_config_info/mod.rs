@@ -0,0 +1,20 @@
+use super::{ChallengeLocalizedName, ChallengeState, ChallengeTracking};
+use crate::enums::ChallengeLevel;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeConfigInfo {
+    pub id: i64,
+    pub localized_names: HashMap<String, ChallengeLocalizedName>,
+    pub state: ChallengeState,
+    pub tracking: Option<ChallengeTracking>,
+    pub start_timestamp: Option<i64>,
+    pub end_timestamp: Option<i64>,
+    pub leaderboard: bool,
+    pub thresholds: HashMap<ChallengeLevel, f64>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
@@ -0,0 +1,15 @@
+use crate::enums::ChallengeLevel;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeInfo {
+    pub challenge_id: i64,
+    pub percentile: f64,
+    pub level: ChallengeLevel,
+    pub value: f64,
+    pub achieved_time: Option<i64>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
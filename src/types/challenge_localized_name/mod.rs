@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeLocalizedName {
+    pub description: String,
+    pub name: String,
+    pub short_description: String,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
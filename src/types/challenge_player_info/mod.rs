@@ -0,0 +1,15 @@
+use super::{ChallengeInfo, ChallengePoints, ChallengePreferences};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengePlayerInfo {
+    pub challenges: Vec<ChallengeInfo>,
+    pub preferences: ChallengePreferences,
+    pub total_points: ChallengePoints,
+    pub category_points: HashMap<String, ChallengePoints>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
@@ -0,0 +1,14 @@
+use crate::enums::ChallengeLevel;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengePoints {
+    pub level: ChallengeLevel,
+    pub current: i64,
+    pub max: i64,
+    pub percentile: Option<f64>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
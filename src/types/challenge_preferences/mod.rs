@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengePreferences {
+    pub banner_accent: Option<String>,
+    pub title: Option<String>,
+    #[serde(default)]
+    pub challenge_ids: Vec<i64>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
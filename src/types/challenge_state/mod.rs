@@ -0,0 +1,12 @@
+fallback_enum! {
+    pub enum ChallengeState {
+        #[strum(serialize = "DISABLED")]
+        Disabled,
+        #[strum(serialize = "HIDDEN")]
+        Hidden,
+        #[strum(serialize = "ENABLED")]
+        Enabled,
+        #[strum(serialize = "ARCHIVED")]
+        Archived,
+    }
+}
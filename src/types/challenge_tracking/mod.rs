@@ -0,0 +1,8 @@
+fallback_enum! {
+    pub enum ChallengeTracking {
+        #[strum(serialize = "LIFETIME")]
+        Lifetime,
+        #[strum(serialize = "SEASON")]
+        Season,
+    }
+}
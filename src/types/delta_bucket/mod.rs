@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter, Result},
+    str::FromStr,
+};
+
+/// A single bucket of a `DeltaSeries`, covering the minutes from `start_minute` up to, but excluding, `end_minute`.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::types::DeltaBucket;
+/// let bucket: DeltaBucket = "30-end".parse().unwrap();
+///
+/// assert_eq!(bucket.start_minute, 30);
+/// assert_eq!(bucket.end_minute, None);
+/// assert!(bucket.contains(42));
+/// assert_eq!(bucket.to_string(), "30-end");
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DeltaBucket {
+    pub start_minute: u32,
+    /// The end of the bucket, or `None` for the last bucket which lasts until the end of the game.
+    pub end_minute: Option<u32>,
+    #[serde(default)]
+    pub value: f32,
+}
+
+impl DeltaBucket {
+    /// Whether `minute` falls within this bucket.
+    pub fn contains(&self, minute: u32) -> bool {
+        let before_end = match self.end_minute {
+            Some(end_minute) => minute < end_minute,
+            None => true,
+        };
+
+        minute >= self.start_minute && before_end
+    }
+}
+
+/// Parses the range of a Riot delta key such as `0-10` or `30-end`, with a `value` of `0.0`.
+impl FromStr for DeltaBucket {
+    type Err = String;
+
+    fn from_str(key: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || format!("invalid delta bucket {:?}", key);
+        let (start, end) = key.split_once('-').ok_or_else(invalid)?;
+        let start_minute = start.parse().map_err(|_| invalid())?;
+        let end_minute = match end {
+            "end" => None,
+            end => Some(end.parse().map_err(|_| invalid())?),
+        };
+
+        Ok(DeltaBucket {
+            start_minute,
+            end_minute,
+            value: 0.0,
+        })
+    }
+}
+
+/// Formats the range of the bucket as a Riot delta key, e.g. `0-10` or `30-end`.
+impl Display for DeltaBucket {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self.end_minute {
+            Some(end_minute) => write!(f, "{}-{}", self.start_minute, end_minute),
+            None => write!(f, "{}-end", self.start_minute),
+        }
+    }
+}
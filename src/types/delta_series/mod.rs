@@ -0,0 +1,109 @@
+use super::DeltaBucket;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Per minute values of a participant timeline, bucketed by game time.
+///
+/// Deserialized from and serialized back to Riot's `{ "0-10": ..., "10-20": ... }` format, with the buckets ordered by
+/// their start minute. Keys that are not a bucket range are kept as received in `unparsed()`.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::types::{DeltaSeries, Match};
+/// # let game: Match = serde_json::from_str(&std::fs::read_to_string(
+/// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+/// # ).unwrap()).unwrap();
+/// let timeline = &game.participants[0].timeline;
+/// let xp_per_minute = timeline.xp_per_min_deltas.as_ref().unwrap();
+///
+/// assert_eq!(xp_per_minute.at_minute(5), Some(380.5));
+/// assert_eq!(xp_per_minute.at_minute(15), Some(512.3));
+/// assert_eq!(xp_per_minute.at_minute(45), Some(450.0));
+///
+/// let starts: Vec<u32> = xp_per_minute
+///     .buckets()
+///     .iter()
+///     .map(|bucket| bucket.start_minute)
+///     .collect();
+///
+/// assert_eq!(starts, [0, 10, 20, 30]);
+/// assert!(xp_per_minute.unparsed().is_empty());
+///
+/// assert_eq!(
+///     serde_json::to_string(xp_per_minute).unwrap(),
+///     r#"{"0-10":380.5,"10-20":512.3,"20-30":601.8,"30-end":450.0}"#,
+/// );
+///
+/// let series: DeltaSeries = serde_json::from_str(r#"{"0-10":1.5,"end-x":2.0}"#).unwrap();
+///
+/// assert_eq!(series.at_minute(5), Some(1.5));
+/// assert_eq!(series.unparsed()["end-x"], 2.0);
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(from = "BTreeMap<String, f32>", into = "BTreeMap<String, f32>")]
+pub struct DeltaSeries {
+    buckets: Vec<DeltaBucket>,
+    unparsed: BTreeMap<String, f32>,
+}
+
+impl DeltaSeries {
+    /// Constructs a series from its buckets, in any order.
+    pub fn new(mut buckets: Vec<DeltaBucket>) -> Self {
+        buckets.sort_by_key(|bucket| bucket.start_minute);
+
+        DeltaSeries {
+            buckets,
+            unparsed: BTreeMap::new(),
+        }
+    }
+
+    /// The buckets of the series, ordered by their start minute.
+    pub fn buckets(&self) -> &[DeltaBucket] {
+        &self.buckets
+    }
+
+    /// Values whose key is not a bucket range, keyed as received.
+    pub fn unparsed(&self) -> &BTreeMap<String, f32> {
+        &self.unparsed
+    }
+
+    /// The value of the bucket containing `minute`, if any.
+    pub fn at_minute(&self, minute: u32) -> Option<f32> {
+        self.buckets
+            .iter()
+            .find(|bucket| bucket.contains(minute))
+            .map(|bucket| bucket.value)
+    }
+}
+
+impl From<BTreeMap<String, f32>> for DeltaSeries {
+    fn from(deltas: BTreeMap<String, f32>) -> Self {
+        let mut buckets = Vec::new();
+        let mut unparsed = BTreeMap::new();
+
+        for (key, value) in deltas {
+            match key.parse::<DeltaBucket>() {
+                Ok(bucket) => buckets.push(DeltaBucket { value, ..bucket }),
+                Err(_) => {
+                    unparsed.insert(key, value);
+                }
+            }
+        }
+
+        DeltaSeries {
+            unparsed,
+            ..DeltaSeries::new(buckets)
+        }
+    }
+}
+
+impl From<DeltaSeries> for BTreeMap<String, f32> {
+    fn from(series: DeltaSeries) -> Self {
+        series
+            .buckets
+            .into_iter()
+            .map(|bucket| (bucket.to_string(), bucket.value))
+            .chain(series.unparsed)
+            .collect()
+    }
+}
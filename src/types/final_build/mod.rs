@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// The items a participant held at the end of the game, obtained through `ParticipantStats::final_build()`.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct FinalBuild {
+    /// Item ids in inventory slot order, skipping empty slots.
+    pub items: Vec<i32>,
+    /// The item id in the trinket slot, if any.
+    pub trinket: Option<i32>,
+}
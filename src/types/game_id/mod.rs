@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+/// A numeric game id as used by the league of legends match-v4 APIs, unique per platform.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::types::GameId;
+/// let id = GameId::from(3700000000);
+///
+/// assert_eq!(id.0, 3700000000);
+/// assert_eq!(id.to_string(), "3700000000");
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(transparent)]
+pub struct GameId(pub i64);
+
+impl From<i64> for GameId {
+    fn from(id: i64) -> Self {
+        GameId(id)
+    }
+}
+
+impl Display for GameId {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -1,9 +1,8 @@
-use serde::{Deserialize, Serialize};
-
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub enum GameResult {
-    #[serde(alias = "Win")]
-    Won,
-    #[serde(alias = "Fail")]
-    Lost,
+fallback_enum! {
+    pub enum GameResult {
+        #[strum(to_string = "Won", serialize = "Win")]
+        Won,
+        #[strum(to_string = "Lost", serialize = "Fail")]
+        Lost,
+    }
 }
@@ -1,14 +1,20 @@
-use serde::{Deserialize, Serialize};
-
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(rename_all = "UPPERCASE")]
-pub enum HighestAchievedSeasonTier {
-    Challenger,
-    Master,
-    Diamond,
-    Platinum,
-    Gold,
-    Silver,
-    Bronze,
-    Unranked,
+fallback_enum! {
+    pub enum HighestAchievedSeasonTier {
+        #[strum(serialize = "CHALLENGER")]
+        Challenger,
+        #[strum(serialize = "MASTER")]
+        Master,
+        #[strum(serialize = "DIAMOND")]
+        Diamond,
+        #[strum(serialize = "PLATINUM")]
+        Platinum,
+        #[strum(serialize = "GOLD")]
+        Gold,
+        #[strum(serialize = "SILVER")]
+        Silver,
+        #[strum(serialize = "BRONZE")]
+        Bronze,
+        #[strum(serialize = "UNRANKED")]
+        Unranked,
+    }
 }
@@ -1,12 +1,54 @@
-use serde::{Deserialize, Serialize};
+use crate::enums::Position;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(rename_all = "UPPERCASE")]
-pub enum Lane {
-    Mid,
-    Middle,
-    Top,
-    Jungle,
-    Bot,
-    Bottom,
+fallback_enum! {
+    /// The lane a participant played in.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::types::Lane;
+    /// assert_eq!(Lane::from("MIDDLE".to_string()), Lane::Middle);
+    ///
+    /// // Lanes added after this version of the crate are kept as received.
+    /// let lane = Lane::from("NONE".to_string());
+    ///
+    /// assert_eq!(lane, Lane::Unknown("NONE".to_string()));
+    /// assert_eq!(String::from(lane), "NONE");
+    /// ```
+    pub enum Lane {
+        #[strum(serialize = "MID")]
+        Mid,
+        #[strum(serialize = "MIDDLE")]
+        Middle,
+        #[strum(serialize = "TOP")]
+        Top,
+        #[strum(serialize = "JUNGLE")]
+        Jungle,
+        #[strum(serialize = "BOT")]
+        Bot,
+        #[strum(serialize = "BOTTOM")]
+        Bottom,
+    }
+}
+
+impl Lane {
+    /// The position matching the lane, merging `Mid` with `Middle` and `Bot` with `Bottom`.
+    ///
+    /// Supports report the bottom lane as well, see `match_analysis::positions` to tell them apart.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{enums::Position, types::Lane};
+    /// assert_eq!(Lane::Mid.position(), Some(Position::Middle));
+    /// assert_eq!(Lane::Middle.position(), Some(Position::Middle));
+    /// assert_eq!(Lane::Unknown("NONE".to_string()).position(), None);
+    /// ```
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            Lane::Top => Some(Position::Top),
+            Lane::Jungle => Some(Position::Jungle),
+            Lane::Mid | Lane::Middle => Some(Position::Middle),
+            Lane::Bot | Lane::Bottom => Some(Position::Bottom),
+            Lane::Unknown(_) => None,
+        }
+    }
 }
@@ -1,15 +1,16 @@
-use super::MiniSeries;
+use super::{MiniSeries, SummonerId};
+use crate::enums::{Division, Queue, Tier};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LeagueEntry {
     pub league_id: String,
-    pub summoner_id: String,
+    pub summoner_id: SummonerId,
     pub summoner_name: String,
-    pub queue_type: String,
-    pub tier: String,
-    pub rank: String,
+    pub queue_type: Queue,
+    pub tier: Tier,
+    pub rank: Division,
     pub league_points: i32,
     pub wins: i32,
     pub losses: i32,
@@ -18,4 +19,7 @@ pub struct LeagueEntry {
     pub fresh_blood: bool,
     pub inactive: bool,
     pub mini_series: Option<MiniSeries>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
 }
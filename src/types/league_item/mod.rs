@@ -1,4 +1,5 @@
-use super::MiniSeries;
+use super::{MiniSeries, SummonerId};
+use crate::enums::Division;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -11,8 +12,11 @@ pub struct LeagueItem {
     pub inactive: bool,
     pub veteran: bool,
     pub hot_streak: bool,
-    pub rank: String,
+    pub rank: Division,
     pub league_points: i32,
     pub losses: i32,
-    pub summoner_id: String,
+    pub summoner_id: SummonerId,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
 }
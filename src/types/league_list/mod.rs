@@ -1,4 +1,5 @@
 use super::LeagueItem;
+use crate::enums::{Queue, Tier};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -6,7 +7,10 @@ use serde::{Deserialize, Serialize};
 pub struct LeagueList {
     pub league_id: String,
     pub entries: Vec<LeagueItem>,
-    pub tier: String,
+    pub tier: Tier,
     pub name: String,
-    pub queue: String,
+    pub queue: Queue,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
 }
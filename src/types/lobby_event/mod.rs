@@ -0,0 +1,13 @@
+use super::SummonerId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LobbyEvent {
+    pub summoner_id: SummonerId,
+    pub event_type: String,
+    pub timestamp: String,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
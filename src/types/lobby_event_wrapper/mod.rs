@@ -0,0 +1,11 @@
+use super::LobbyEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LobbyEventWrapper {
+    pub event_list: Vec<LobbyEvent>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
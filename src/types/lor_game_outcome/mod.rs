@@ -0,0 +1,10 @@
+fallback_enum! {
+    pub enum LorGameOutcome {
+        #[strum(serialize = "win")]
+        Win,
+        #[strum(serialize = "loss")]
+        Loss,
+        #[strum(serialize = "tie")]
+        Tie,
+    }
+}
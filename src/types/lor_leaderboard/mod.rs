@@ -0,0 +1,11 @@
+use super::LorLeaderboardPlayer;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LorLeaderboard {
+    pub players: Vec<LorLeaderboardPlayer>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
@@ -0,0 +1,11 @@
+use super::{LorMatchInfo, LorMatchMetadata};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LorMatch {
+    pub metadata: LorMatchMetadata,
+    pub info: LorMatchInfo,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
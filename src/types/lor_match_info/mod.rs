@@ -0,0 +1,15 @@
+use super::LorMatchPlayer;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LorMatchInfo {
+    pub game_mode: String,
+    pub game_type: String,
+    pub game_start_time_utc: String,
+    pub game_version: String,
+    pub players: Vec<LorMatchPlayer>,
+    pub total_turn_count: i32,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
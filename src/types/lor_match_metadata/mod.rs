@@ -0,0 +1,12 @@
+use super::{MatchId, Puuid};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LorMatchMetadata {
+    pub data_version: String,
+    pub match_id: MatchId,
+    pub participants: Vec<Puuid>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
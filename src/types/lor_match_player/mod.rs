@@ -0,0 +1,15 @@
+use super::{LorGameOutcome, Puuid};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LorMatchPlayer {
+    pub puuid: Puuid,
+    pub deck_id: String,
+    pub deck_code: String,
+    pub factions: Vec<String>,
+    pub game_outcome: LorGameOutcome,
+    pub order_of_play: i32,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
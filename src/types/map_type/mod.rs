@@ -0,0 +1,10 @@
+fallback_enum! {
+    pub enum MapType {
+        #[strum(serialize = "SUMMONERS_RIFT")]
+        SummonersRift,
+        #[strum(serialize = "TWISTED_TREELINE")]
+        TwistedTreeline,
+        #[strum(serialize = "HOWLING_ABYSS")]
+        HowlingAbyss,
+    }
+}
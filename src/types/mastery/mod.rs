@@ -5,4 +5,7 @@ use serde::{Deserialize, Serialize};
 pub struct Mastery {
     pub rank: i32,
     pub mastery_id: i32,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
 }
@@ -1,20 +1,150 @@
-use super::{Participant, ParticipantIdentity, TeamStats};
+use super::{
+    AccountId, GameId, MatchPlayer, Participant, ParticipantIdentity, PlatformId, SummonerId,
+    TeamStats,
+};
+use crate::{enums::QueueId, match_analysis::positions};
 use serde::{Deserialize, Serialize};
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Match {
-    pub game_id: i64,
+    pub game_id: GameId,
     pub participant_identities: Vec<ParticipantIdentity>,
-    pub queue_id: i32,
+    pub queue_id: QueueId,
     pub game_type: String,
     pub game_duration: i64,
     pub teams: Vec<TeamStats>,
-    pub platform_id: String,
+    pub platform_id: PlatformId,
     pub game_creation: i64,
     pub season_id: i32,
     pub game_version: String,
     pub map_id: i32,
     pub game_mode: String,
     pub participants: Vec<Participant>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
+
+impl Match {
+    /// The time the game was created.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::types::Match;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// # let game: Match = serde_json::from_str(&std::fs::read_to_string(
+    /// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+    /// # ).unwrap()).unwrap();
+    /// assert_eq!(game.creation_time(), UNIX_EPOCH + Duration::from_secs(1_610_000_000));
+    /// ```
+    pub fn creation_time(&self) -> SystemTime {
+        crate::time::from_epoch_millis(self.game_creation)
+    }
+
+    /// The length of the game.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::types::Match;
+    /// # use std::time::Duration;
+    /// # let game: Match = serde_json::from_str(&std::fs::read_to_string(
+    /// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+    /// # ).unwrap()).unwrap();
+    /// assert_eq!(game.duration(), Duration::from_secs(30 * 60 + 15));
+    /// ```
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs(self.game_duration.max(0) as u64)
+    }
+
+    /// Every participant joined with their identity and team, in participant order.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::types::Match;
+    /// # let game: Match = serde_json::from_str(&std::fs::read_to_string(
+    /// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+    /// # ).unwrap()).unwrap();
+    /// let winners: Vec<&str> = game
+    ///     .players()
+    ///     .filter(|player| player.won())
+    ///     .filter_map(|player| player.summoner_name())
+    ///     .collect();
+    ///
+    /// assert_eq!(winners, ["Ironclad", "Thornback", "Mistveil", "Quickshot", "Lanternward"]);
+    /// ```
+    pub fn players(&self) -> impl Iterator<Item = MatchPlayer<'_>> {
+        let positions = Arc::new(positions(self));
+
+        self.participants
+            .iter()
+            .map(move |participant| MatchPlayer::new(self, participant, Arc::clone(&positions)))
+    }
+
+    /// The participant with the given participant id.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::types::Match;
+    /// # let game: Match = serde_json::from_str(&std::fs::read_to_string(
+    /// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+    /// # ).unwrap()).unwrap();
+    /// let player = game.player(3).unwrap();
+    ///
+    /// assert_eq!(player.summoner_name(), Some("Mistveil"));
+    /// ```
+    pub fn player(&self, participant_id: i32) -> Option<MatchPlayer<'_>> {
+        self.players()
+            .find(|player| player.participant.participant_id == participant_id)
+    }
+
+    /// The participant with the given summoner id.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::types::{Match, SummonerId};
+    /// # let game: Match = serde_json::from_str(&std::fs::read_to_string(
+    /// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+    /// # ).unwrap()).unwrap();
+    /// let player = game
+    ///     .player_by_summoner_id(&SummonerId::from("SUMMONER_ID_07"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(player.participant.participant_id, 7);
+    /// ```
+    pub fn player_by_summoner_id(&self, summoner_id: &SummonerId) -> Option<MatchPlayer<'_>> {
+        self.players().find(|player| {
+            matches!(player.player, Some(identity) if &identity.summoner_id == summoner_id)
+        })
+    }
+
+    /// The participant with the given account id, matching both the original and the current account of the player.
+    ///
+    /// Match v4 identities do not carry a PUUID, resolve it to an account id through the summoner API instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::types::{AccountId, Match};
+    /// # let game: Match = serde_json::from_str(&std::fs::read_to_string(
+    /// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+    /// # ).unwrap()).unwrap();
+    /// let player = game
+    ///     .player_by_account_id(&AccountId::from("ACCOUNT_ID_04"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(player.participant.participant_id, 4);
+    /// ```
+    pub fn player_by_account_id(&self, account_id: &AccountId) -> Option<MatchPlayer<'_>> {
+        self.players().find(|player| {
+            matches!(
+                player.player,
+                Some(identity)
+                    if &identity.account_id == account_id || &identity.current_account_id == account_id
+            )
+        })
+    }
 }
@@ -0,0 +1,6 @@
+string_id! {
+    /// A match id as used by the regionally routed match APIs, e.g. `NA1_00000`.
+    ///
+    /// The league of legends match-v4 APIs use numeric game ids instead.
+    MatchId
+}
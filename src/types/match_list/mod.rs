@@ -8,4 +8,7 @@ pub struct MatchList {
     pub total_games: i32,
     pub end_index: i32,
     pub matches: Vec<MatchReference>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
 }
@@ -0,0 +1,107 @@
+use super::{Match, Participant, Player, TeamStats};
+use crate::enums::Position;
+use std::{collections::HashMap, sync::Arc};
+
+/// A participant of a `Match` joined with their identity and team.
+///
+/// Obtained through `Match::players()` and the `Match::player_by_*` lookups, which compute the positions of the match
+/// once and share them between the players.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::Position,
+/// #    types::{Match, SummonerId},
+/// # };
+/// # let game: Match = serde_json::from_str(&std::fs::read_to_string(
+/// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+/// # ).unwrap()).unwrap();
+/// let top = game
+///     .player_by_summoner_id(&SummonerId::from("SUMMONER_ID_01"))
+///     .unwrap();
+///
+/// assert_eq!(top.summoner_name(), Some("Ironclad"));
+/// assert_eq!(top.participant.stats.kills, 5);
+/// assert!(top.won());
+///
+/// let opponent = top.lane_opponent().unwrap();
+///
+/// assert_eq!(opponent.summoner_name(), Some("Stoneroot"));
+/// assert!(!opponent.won());
+///
+/// let support = game.player(5).unwrap();
+///
+/// assert_eq!(support.position(), Some(Position::Utility));
+/// assert_eq!(support.lane_opponent().unwrap().summoner_name(), Some("Gentlepaw"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MatchPlayer<'a> {
+    game: &'a Match,
+    positions: Arc<HashMap<i32, Position>>,
+    pub participant: &'a Participant,
+    /// The identity of the participant, missing for bots and in some custom games.
+    pub player: Option<&'a Player>,
+    pub team: Option<&'a TeamStats>,
+}
+
+impl<'a> MatchPlayer<'a> {
+    pub(crate) fn new(
+        game: &'a Match,
+        participant: &'a Participant,
+        positions: Arc<HashMap<i32, Position>>,
+    ) -> Self {
+        let player = game
+            .participant_identities
+            .iter()
+            .find(|identity| identity.participant_id == participant.participant_id)
+            .and_then(|identity| identity.player.as_ref());
+        let team = game
+            .teams
+            .iter()
+            .find(|team| team.team_id == participant.team_id);
+
+        MatchPlayer {
+            game,
+            positions,
+            participant,
+            player,
+            team,
+        }
+    }
+
+    /// The summoner name of the player, if their identity is known.
+    pub fn summoner_name(&self) -> Option<&'a str> {
+        self.player.map(|player| player.summoner_name.as_str())
+    }
+
+    /// Whether the team of the player won the game.
+    pub fn won(&self) -> bool {
+        self.participant.stats.win
+    }
+
+    /// The normalized position of the player, `None` outside of Summoner's Rift, see `match_analysis::positions`.
+    pub fn position(&self) -> Option<Position> {
+        self.positions
+            .get(&self.participant.participant_id)
+            .copied()
+    }
+
+    /// The player of the opposing team in the same position, if there is exactly one.
+    pub fn lane_opponent(&self) -> Option<MatchPlayer<'a>> {
+        let positions = &self.positions;
+        let position = positions.get(&self.participant.participant_id)?;
+        let mut opponents = self.game.participants.iter().filter(|participant| {
+            participant.team_id != self.participant.team_id
+                && positions.get(&participant.participant_id) == Some(position)
+        });
+
+        match (opponents.next(), opponents.next()) {
+            (Some(opponent), None) => Some(MatchPlayer::new(
+                self.game,
+                opponent,
+                Arc::clone(&self.positions),
+            )),
+            _ => None,
+        }
+    }
+}
@@ -1,14 +1,38 @@
+use super::{GameId, PlatformId};
+use crate::enums::QueueId;
 use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MatchReference {
-    pub game_id: i64,
+    pub game_id: GameId,
     pub role: String,
     pub season: i32,
-    pub platform_id: String,
+    pub platform_id: PlatformId,
     pub champion: i32,
-    pub queue: i32,
+    pub queue: QueueId,
     pub lane: String,
     pub timestamp: i64,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
+
+impl MatchReference {
+    /// The time the referenced game was created.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::types::MatchReference;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// # let reference: MatchReference = serde_json::from_str(r#"{
+    /// #     "gameId": 3700000001, "role": "SOLO", "season": 13, "platformId": "NA1",
+    /// #     "champion": 86, "queue": 420, "lane": "TOP", "timestamp": 1610000000000
+    /// # }"#).unwrap();
+    /// assert_eq!(reference.creation_time(), UNIX_EPOCH + Duration::from_secs(1_610_000_000));
+    /// ```
+    pub fn creation_time(&self) -> SystemTime {
+        crate::time::from_epoch_millis(self.timestamp)
+    }
 }
@@ -1,49 +1,227 @@
 //! Contains all the return types from the Riot Api.
 
+/// Declares a newtype for a string id of the Riot Api, with conversions from strings and a `Display` impl.
+macro_rules! string_id {
+    ($(#[$attribute:meta])* $name:ident) => {
+        $(#[$attribute])*
+        ///
+        /// # Example
+        /// ```rust
+        #[doc = concat!("# use league_of_legends_client::types::", stringify!($name), ";")]
+        #[doc = concat!("let id = ", stringify!($name), "::from(\"EXAMPLE_ID\");")]
+        ///
+        /// assert_eq!(id.as_str(), "EXAMPLE_ID");
+        /// assert_eq!(id.to_string(), "EXAMPLE_ID");
+        /// ```
+        #[derive(
+            Clone,
+            Debug,
+            serde::Deserialize,
+            Eq,
+            Hash,
+            Ord,
+            PartialEq,
+            PartialOrd,
+            serde::Serialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub String);
+
+        impl $name {
+            /// The id as a string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                $name(id.to_string())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                $name(id)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+mod account_id;
+mod apex_player_info;
+mod challenge_config_info;
+mod challenge_info;
+mod challenge_localized_name;
+mod challenge_player_info;
+mod challenge_points;
+mod challenge_preferences;
+mod challenge_state;
+mod challenge_tracking;
+mod delta_bucket;
+mod delta_series;
 mod error;
+mod final_build;
+mod game_id;
 mod game_result;
 mod highest_achieved_season_tier;
 mod lane;
 mod league_entry;
 mod league_item;
 mod league_list;
+mod lobby_event;
+mod lobby_event_wrapper;
+mod lor_game_outcome;
+mod lor_leaderboard;
+mod lor_leaderboard_player;
+mod lor_match;
+mod lor_match_info;
+mod lor_match_metadata;
+mod lor_match_player;
+mod map_type;
 mod mastery;
 mod r#match;
+mod match_id;
 mod match_list;
+mod match_player;
 mod match_reference;
 mod mini_series;
 mod participant;
 mod participant_identity;
 mod participant_stats;
 mod participant_timeline;
+mod pick_type;
+mod platform_id;
 mod player;
+mod provider_registration_parameters;
+mod puuid;
+mod rated_tier;
 mod role;
 mod rune;
+mod rune_page;
+mod rune_selection;
+mod spectator_type;
+mod static_champion;
+mod static_image;
+mod static_item;
+mod static_item_gold;
+mod static_map;
+mod static_queue;
+mod static_rune;
+mod static_rune_slot;
+mod static_rune_style;
+mod static_summoner_spell;
 mod summoner;
+mod summoner_id;
 mod team;
 mod team_bands;
 mod team_stats;
+mod tft_companion;
+mod tft_league_entry;
+mod tft_match;
+mod tft_match_info;
+mod tft_match_metadata;
+mod tft_participant;
+mod tft_trait;
+mod tft_unit;
+mod top_rated_ladder_entry;
+mod tournament_code;
+mod tournament_code_parameters;
+mod tournament_code_update_parameters;
+mod tournament_region;
+mod tournament_registration_parameters;
 
+pub use account_id::AccountId;
+pub use apex_player_info::ApexPlayerInfo;
+pub use challenge_config_info::ChallengeConfigInfo;
+pub use challenge_info::ChallengeInfo;
+pub use challenge_localized_name::ChallengeLocalizedName;
+pub use challenge_player_info::ChallengePlayerInfo;
+pub use challenge_points::ChallengePoints;
+pub use challenge_preferences::ChallengePreferences;
+pub use challenge_state::ChallengeState;
+pub use challenge_tracking::ChallengeTracking;
+pub use delta_bucket::DeltaBucket;
+pub use delta_series::DeltaSeries;
 pub use error::Error;
+pub use final_build::FinalBuild;
+pub use game_id::GameId;
 pub use game_result::GameResult;
 pub use highest_achieved_season_tier::HighestAchievedSeasonTier;
 pub use lane::Lane;
 pub use league_entry::LeagueEntry;
 pub use league_item::LeagueItem;
 pub use league_list::LeagueList;
+pub use lobby_event::LobbyEvent;
+pub use lobby_event_wrapper::LobbyEventWrapper;
+pub use lor_game_outcome::LorGameOutcome;
+pub use lor_leaderboard::LorLeaderboard;
+pub use lor_leaderboard_player::LorLeaderboardPlayer;
+pub use lor_match::LorMatch;
+pub use lor_match_info::LorMatchInfo;
+pub use lor_match_metadata::LorMatchMetadata;
+pub use lor_match_player::LorMatchPlayer;
+pub use map_type::MapType;
 pub use mastery::Mastery;
+pub use match_id::MatchId;
 pub use match_list::MatchList;
+pub use match_player::MatchPlayer;
 pub use match_reference::MatchReference;
 pub use mini_series::MiniSeries;
 pub use participant::Participant;
 pub use participant_identity::ParticipantIdentity;
 pub use participant_stats::ParticipantStats;
 pub use participant_timeline::ParticipantTimeline;
+pub use pick_type::PickType;
+pub use platform_id::PlatformId;
 pub use player::Player;
+pub use provider_registration_parameters::ProviderRegistrationParameters;
+pub use puuid::Puuid;
 pub use r#match::Match;
+pub use rated_tier::RatedTier;
 pub use role::Role;
 pub use rune::Rune;
+pub use rune_page::RunePage;
+pub use rune_selection::RuneSelection;
+pub use spectator_type::SpectatorType;
+pub use static_champion::StaticChampion;
+pub use static_image::StaticImage;
+pub use static_item::StaticItem;
+pub use static_item_gold::StaticItemGold;
+pub use static_map::StaticMap;
+pub use static_queue::StaticQueue;
+pub use static_rune::StaticRune;
+pub use static_rune_slot::StaticRuneSlot;
+pub use static_rune_style::StaticRuneStyle;
+pub use static_summoner_spell::StaticSummonerSpell;
 pub use summoner::Summoner;
+pub use summoner_id::SummonerId;
 pub use team::Team;
 pub use team_bands::TeamBands;
 pub use team_stats::TeamStats;
+pub use tft_companion::TftCompanion;
+pub use tft_league_entry::TftLeagueEntry;
+pub use tft_match::TftMatch;
+pub use tft_match_info::TftMatchInfo;
+pub use tft_match_metadata::TftMatchMetadata;
+pub use tft_participant::TftParticipant;
+pub use tft_trait::TftTrait;
+pub use tft_unit::TftUnit;
+pub use top_rated_ladder_entry::TopRatedLadderEntry;
+pub use tournament_code::TournamentCode;
+pub use tournament_code_parameters::TournamentCodeParameters;
+pub use tournament_code_update_parameters::TournamentCodeUpdateParameters;
+pub use tournament_region::TournamentRegion;
+pub use tournament_registration_parameters::TournamentRegistrationParameters;
+
+/// Response fields that are not modeled by this crate, keyed by their JSON name.
+///
+/// Every response type carries these in its `extra` field when the `extra_fields` feature is enabled.
+#[cfg(feature = "extra_fields")]
+pub type ExtraFields = std::collections::HashMap<String, serde_json::Value>;
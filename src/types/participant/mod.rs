@@ -16,4 +16,7 @@ pub struct Participant {
     pub spell_2_id: i32,
     pub highest_achieved_season_tier: Option<HighestAchievedSeasonTier>,
     pub masteries: Option<Vec<Mastery>>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
 }
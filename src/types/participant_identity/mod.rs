@@ -6,4 +6,7 @@ use serde::{Deserialize, Serialize};
 pub struct ParticipantIdentity {
     pub participant_id: i32,
     pub player: Option<Player>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
 }
@@ -1,3 +1,4 @@
+use super::{FinalBuild, RunePage, RuneSelection};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -114,4 +115,100 @@ pub struct ParticipantStats {
     pub stat_perk_0: i32,
     pub stat_perk_1: i32,
     pub stat_perk_2: i32,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
+
+impl ParticipantStats {
+    /// The items held at the end of the game.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::types::Match;
+    /// # let game: Match = serde_json::from_str(&std::fs::read_to_string(
+    /// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+    /// # ).unwrap()).unwrap();
+    /// let build = game.participants[0].stats.final_build();
+    ///
+    /// assert_eq!(build.items, [3071, 3047, 3053, 3742, 1011]);
+    /// assert_eq!(build.trinket, Some(3340));
+    /// ```
+    pub fn final_build(&self) -> FinalBuild {
+        let items = [
+            self.item_0,
+            self.item_1,
+            self.item_2,
+            self.item_3,
+            self.item_4,
+            self.item_5,
+        ];
+
+        FinalBuild {
+            items: items.iter().copied().filter(|item| *item != 0).collect(),
+            trinket: Some(self.item_6).filter(|item| *item != 0),
+        }
+    }
+
+    /// The runes played, or `None` for games without a rune page, such as games played before runes reforged.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::types::Match;
+    /// # let game: Match = serde_json::from_str(&std::fs::read_to_string(
+    /// #     concat!(env!("CARGO_MANIFEST_DIR"), "/data/fixtures/match.json"),
+    /// # ).unwrap()).unwrap();
+    /// let runes = game.participants[0].stats.rune_page().unwrap();
+    ///
+    /// assert_eq!(runes.primary_style, 8000);
+    /// assert_eq!(runes.keystone.id, 8005);
+    /// assert_eq!(runes.keystone.vars, [1450, 980, 470]);
+    /// assert_eq!(runes.secondary_runes.len(), 2);
+    /// assert_eq!(runes.stat_shards, [5005, 5008, 5002]);
+    /// ```
+    pub fn rune_page(&self) -> Option<RunePage> {
+        if self.perk_0 == 0 {
+            return None;
+        }
+
+        let runes = [
+            (
+                self.perk_0,
+                [self.perk_0_var_1, self.perk_0_var_2, self.perk_0_var_3],
+            ),
+            (
+                self.perk_1,
+                [self.perk_1_var_1, self.perk_1_var_2, self.perk_1_var_3],
+            ),
+            (
+                self.perk_2,
+                [self.perk_2_var_1, self.perk_2_var_2, self.perk_2_var_3],
+            ),
+            (
+                self.perk_3,
+                [self.perk_3_var_1, self.perk_3_var_2, self.perk_3_var_3],
+            ),
+            (
+                self.perk_4,
+                [self.perk_4_var_1, self.perk_4_var_2, self.perk_4_var_3],
+            ),
+            (
+                self.perk_5,
+                [self.perk_5_var_1, self.perk_5_var_2, self.perk_5_var_3],
+            ),
+        ];
+        let mut runes = runes.iter().map(|(id, vars)| RuneSelection {
+            id: *id,
+            vars: *vars,
+        });
+
+        Some(RunePage {
+            primary_style: self.perk_primary_style,
+            sub_style: self.perk_sub_style,
+            keystone: runes.next()?,
+            primary_runes: runes.by_ref().take(3).collect(),
+            secondary_runes: runes.collect(),
+            stat_shards: [self.stat_perk_0, self.stat_perk_1, self.stat_perk_2],
+        })
+    }
 }
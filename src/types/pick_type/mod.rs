@@ -0,0 +1,12 @@
+fallback_enum! {
+    pub enum PickType {
+        #[strum(serialize = "BLIND_PICK")]
+        BlindPick,
+        #[strum(serialize = "DRAFT_MODE")]
+        DraftMode,
+        #[strum(serialize = "ALL_RANDOM")]
+        AllRandom,
+        #[strum(serialize = "TOURNAMENT_DRAFT")]
+        TournamentDraft,
+    }
+}
@@ -0,0 +1,29 @@
+use crate::enums::Region;
+
+string_id! {
+    /// A platform id as found in the match types, e.g. `NA1`.
+    ///
+    /// The raw platform id is kept, so platforms that are not known to this crate do not fail deserialization.
+    PlatformId
+}
+
+impl PlatformId {
+    /// The region of this platform, if it is known to this crate.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{enums::Region, types::PlatformId};
+    /// assert_eq!(PlatformId::from("EUW1").region(), Some(Region::EuropeWest));
+    /// assert_eq!(PlatformId::from("ME1").region(), None);
+    /// assert_eq!(PlatformId::from(Region::NorthAmerica).region(), Some(Region::NorthAmerica));
+    /// ```
+    pub fn region(&self) -> Option<Region> {
+        self.0.parse().ok()
+    }
+}
+
+impl From<Region> for PlatformId {
+    fn from(region: Region) -> Self {
+        PlatformId::from(region.platform_id())
+    }
+}
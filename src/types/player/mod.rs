@@ -1,14 +1,18 @@
+use super::{AccountId, PlatformId, SummonerId};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Player {
     pub profile_icon: i32,
-    pub account_id: String,
+    pub account_id: AccountId,
     pub match_history_uri: String,
-    pub current_account_id: String,
-    pub current_platform_id: String,
+    pub current_account_id: AccountId,
+    pub current_platform_id: PlatformId,
     pub summoner_name: String,
-    pub summoner_id: String,
-    pub platform_id: String,
+    pub summoner_id: SummonerId,
+    pub platform_id: PlatformId,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
 }
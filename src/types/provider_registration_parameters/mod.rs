@@ -0,0 +1,9 @@
+use super::TournamentRegion;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderRegistrationParameters {
+    pub region: TournamentRegion,
+    pub url: String,
+}
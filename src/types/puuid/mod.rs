@@ -0,0 +1,4 @@
+string_id! {
+    /// An encrypted PUUID, unique across all regions.
+    Puuid
+}
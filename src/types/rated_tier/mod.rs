@@ -0,0 +1,14 @@
+fallback_enum! {
+    pub enum RatedTier {
+        #[strum(serialize = "ORANGE")]
+        Orange,
+        #[strum(serialize = "PURPLE")]
+        Purple,
+        #[strum(serialize = "BLUE")]
+        Blue,
+        #[strum(serialize = "GREEN")]
+        Green,
+        #[strum(serialize = "GRAY")]
+        Gray,
+    }
+}
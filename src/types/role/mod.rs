@@ -1,13 +1,14 @@
-use serde::{Deserialize, Serialize};
-
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(rename_all = "UPPERCASE")]
-pub enum Role {
-    Duo,
-    None,
-    Solo,
-    #[serde(alias = "DUO_CARRY")]
-    DuoCarry,
-    #[serde(alias = "DUO_SUPPORT")]
-    DuoSupport,
+fallback_enum! {
+    pub enum Role {
+        #[strum(serialize = "DUO")]
+        Duo,
+        #[strum(serialize = "NONE")]
+        None,
+        #[strum(serialize = "SOLO")]
+        Solo,
+        #[strum(to_string = "DUO_CARRY", serialize = "DUOCARRY")]
+        DuoCarry,
+        #[strum(to_string = "DUO_SUPPORT", serialize = "DUOSUPPORT")]
+        DuoSupport,
+    }
 }
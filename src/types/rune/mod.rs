@@ -5,4 +5,7 @@ use serde::{Deserialize, Serialize};
 pub struct Rune {
     pub rune_id: i32,
     pub rank: i32,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
 }
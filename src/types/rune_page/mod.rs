@@ -0,0 +1,16 @@
+use super::RuneSelection;
+use serde::{Deserialize, Serialize};
+
+/// The runes a participant played with, obtained through `ParticipantStats::rune_page()`.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RunePage {
+    pub primary_style: i32,
+    pub sub_style: i32,
+    pub keystone: RuneSelection,
+    /// The three minor runes of the primary style.
+    pub primary_runes: Vec<RuneSelection>,
+    /// The two runes of the secondary style.
+    pub secondary_runes: Vec<RuneSelection>,
+    /// The offense, flex and defense stat shard ids.
+    pub stat_shards: [i32; 3],
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+/// A rune chosen in a `RunePage`, with the end of game values tracked for it, such as damage dealt or healing done.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RuneSelection {
+    pub id: i32,
+    pub vars: [i32; 3],
+}
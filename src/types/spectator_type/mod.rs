@@ -0,0 +1,10 @@
+fallback_enum! {
+    pub enum SpectatorType {
+        #[strum(serialize = "NONE")]
+        None,
+        #[strum(serialize = "LOBBYONLY")]
+        LobbyOnly,
+        #[strum(serialize = "ALL")]
+        All,
+    }
+}
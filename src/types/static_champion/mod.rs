@@ -0,0 +1,28 @@
+use super::StaticImage;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StaticChampion {
+    /// Champion key, e.g. `MonkeyKing`.
+    pub id: String,
+    /// Numeric champion ID as a string, e.g. `62`.
+    pub key: String,
+    pub name: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub blurb: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub partype: String,
+    #[serde(default)]
+    pub image: StaticImage,
+    #[serde(default)]
+    pub stats: HashMap<String, f64>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
@@ -0,0 +1,31 @@
+use super::{StaticImage, StaticItemGold};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StaticItem {
+    /// Numeric item ID, filled in from the key of the Data Dragon item map.
+    #[serde(default)]
+    pub id: i32,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub plaintext: String,
+    #[serde(default)]
+    pub gold: StaticItemGold,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub from: Vec<String>,
+    #[serde(default)]
+    pub into: Vec<String>,
+    #[serde(default)]
+    pub image: StaticImage,
+    #[serde(default)]
+    pub stats: HashMap<String, f64>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StaticRune {
+    pub id: i32,
+    pub key: String,
+    #[serde(default)]
+    pub icon: String,
+    pub name: String,
+    #[serde(default)]
+    pub short_desc: String,
+    #[serde(default)]
+    pub long_desc: String,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
@@ -0,0 +1,16 @@
+use super::StaticRuneSlot;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StaticRuneStyle {
+    pub id: i32,
+    pub key: String,
+    #[serde(default)]
+    pub icon: String,
+    pub name: String,
+    pub slots: Vec<StaticRuneSlot>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
@@ -0,0 +1,25 @@
+use super::StaticImage;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StaticSummonerSpell {
+    /// Summoner spell key, e.g. `SummonerFlash`.
+    pub id: String,
+    /// Numeric summoner spell ID as a string, e.g. `4`.
+    pub key: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub cooldown: Vec<f64>,
+    #[serde(default)]
+    pub summoner_level: i32,
+    #[serde(default)]
+    pub modes: Vec<String>,
+    #[serde(default)]
+    pub image: StaticImage,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
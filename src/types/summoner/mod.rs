@@ -1,13 +1,36 @@
+use super::{AccountId, Puuid, SummonerId};
 use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Summoner {
-    pub account_id: String,
+    pub account_id: AccountId,
     pub profile_icon_id: i32,
     pub revision_date: i64,
     pub name: String,
-    pub id: String,
-    pub puuid: String,
+    pub id: SummonerId,
+    pub puuid: Puuid,
     pub summoner_level: i32,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
+
+impl Summoner {
+    /// The time the summoner was last modified.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::types::Summoner;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// # let summoner: Summoner = serde_json::from_str(r#"{
+    /// #     "accountId": "ACCOUNT_ID", "profileIconId": 4001, "revisionDate": 1610000000000,
+    /// #     "name": "Ironclad", "id": "SUMMONER_ID", "puuid": "PUUID", "summonerLevel": 187
+    /// # }"#).unwrap();
+    /// assert_eq!(summoner.revision_time(), UNIX_EPOCH + Duration::from_secs(1_610_000_000));
+    /// ```
+    pub fn revision_time(&self) -> SystemTime {
+        crate::time::from_epoch_millis(self.revision_date)
+    }
 }
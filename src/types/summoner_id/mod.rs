@@ -0,0 +1,4 @@
+string_id! {
+    /// An encrypted summoner id, unique per region.
+    SummonerId
+}
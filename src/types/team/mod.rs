@@ -1,8 +1,41 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize_repr, Deserialize_repr, PartialEq, Debug)]
-#[repr(u8)]
+/// The side of the map a team played on.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::types::Team;
+/// assert_eq!(Team::from(100), Team::Blue);
+///
+/// // Teams added after this version of the crate are kept as received.
+/// assert_eq!(Team::from(300), Team::Unknown(300));
+/// assert_eq!(u16::from(Team::Unknown(300)), 300);
+/// ```
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(from = "u16", into = "u16")]
 pub enum Team {
-    Blue = 100,
-    Red = 200,
+    Blue,
+    Red,
+    /// A team id this version of the crate does not know about, kept as received.
+    Unknown(u16),
+}
+
+impl From<u16> for Team {
+    fn from(id: u16) -> Self {
+        match id {
+            100 => Team::Blue,
+            200 => Team::Red,
+            id => Team::Unknown(id),
+        }
+    }
+}
+
+impl From<Team> for u16 {
+    fn from(team: Team) -> Self {
+        match team {
+            Team::Blue => 100,
+            Team::Red => 200,
+            Team::Unknown(id) => id,
+        }
+    }
 }
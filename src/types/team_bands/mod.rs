@@ -5,4 +5,7 @@ use serde::{Deserialize, Serialize};
 pub struct TeamBands {
     pub champion_id: i32,
     pub pick_turn: i32,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
 }
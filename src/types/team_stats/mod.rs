@@ -20,4 +20,7 @@ pub struct TeamStats {
     pub first_rift_herald: bool,
     pub team_id: Team,
     pub win: GameResult,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
 }
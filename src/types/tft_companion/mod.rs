@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TftCompanion {
+    #[serde(rename = "content_ID")]
+    pub content_id: String,
+    #[serde(rename = "skin_ID")]
+    pub skin_id: i32,
+    pub species: String,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
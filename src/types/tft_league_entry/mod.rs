@@ -0,0 +1,27 @@
+use super::{MiniSeries, RatedTier, SummonerId};
+use crate::enums::{Division, Tier};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TftLeagueEntry {
+    pub league_id: Option<String>,
+    pub summoner_id: SummonerId,
+    pub summoner_name: String,
+    pub queue_type: String,
+    pub rated_tier: Option<RatedTier>,
+    pub rated_rating: Option<i32>,
+    pub tier: Option<Tier>,
+    pub rank: Option<Division>,
+    pub league_points: Option<i32>,
+    pub wins: i32,
+    pub losses: i32,
+    pub hot_streak: Option<bool>,
+    pub veteran: Option<bool>,
+    pub fresh_blood: Option<bool>,
+    pub inactive: Option<bool>,
+    pub mini_series: Option<MiniSeries>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
@@ -0,0 +1,11 @@
+use super::{TftMatchInfo, TftMatchMetadata};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TftMatch {
+    pub metadata: TftMatchMetadata,
+    pub info: TftMatchInfo,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
@@ -0,0 +1,51 @@
+use super::TftParticipant;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TftMatchInfo {
+    pub game_datetime: i64,
+    pub game_length: f32,
+    pub game_variation: Option<String>,
+    pub game_version: String,
+    pub participants: Vec<TftParticipant>,
+    pub queue_id: i32,
+    pub tft_set_number: i32,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
+
+impl TftMatchInfo {
+    /// The time the game was played.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::types::TftMatchInfo;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// # let info: TftMatchInfo = serde_json::from_str(r#"{
+    /// #     "game_datetime": 1610000000000, "game_length": 2012.5, "game_version": "Version 11.1",
+    /// #     "participants": [], "queue_id": 1100, "tft_set_number": 4
+    /// # }"#).unwrap();
+    /// assert_eq!(info.creation_time(), UNIX_EPOCH + Duration::from_secs(1_610_000_000));
+    /// ```
+    pub fn creation_time(&self) -> SystemTime {
+        crate::time::from_epoch_millis(self.game_datetime)
+    }
+
+    /// The length of the game.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::types::TftMatchInfo;
+    /// # use std::time::Duration;
+    /// # let info: TftMatchInfo = serde_json::from_str(r#"{
+    /// #     "game_datetime": 1610000000000, "game_length": 2012.5, "game_version": "Version 11.1",
+    /// #     "participants": [], "queue_id": 1100, "tft_set_number": 4
+    /// # }"#).unwrap();
+    /// assert_eq!(info.duration(), Duration::from_millis(2_012_500));
+    /// ```
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f32(self.game_length.max(0.0))
+    }
+}
@@ -0,0 +1,22 @@
+use super::{Puuid, TftCompanion, TftTrait, TftUnit};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TftParticipant {
+    #[serde(default)]
+    pub augments: Vec<String>,
+    pub companion: TftCompanion,
+    pub gold_left: i32,
+    pub last_round: i32,
+    pub level: i32,
+    pub placement: i32,
+    pub players_eliminated: i32,
+    pub puuid: Puuid,
+    pub time_eliminated: f32,
+    pub total_damage_to_players: i32,
+    pub traits: Vec<TftTrait>,
+    pub units: Vec<TftUnit>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TftTrait {
+    pub name: String,
+    pub num_units: i32,
+    pub style: i32,
+    pub tier_current: i32,
+    pub tier_total: i32,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TftUnit {
+    #[serde(default)]
+    pub items: Vec<i32>,
+    #[serde(default, rename = "itemNames")]
+    pub item_names: Vec<String>,
+    pub character_id: String,
+    pub chosen: Option<String>,
+    pub name: String,
+    pub rarity: i32,
+    pub tier: i32,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
@@ -0,0 +1,16 @@
+use super::{RatedTier, SummonerId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopRatedLadderEntry {
+    pub summoner_id: SummonerId,
+    pub summoner_name: String,
+    pub rated_tier: RatedTier,
+    pub rated_rating: i32,
+    pub wins: i32,
+    pub previous_update_ladder_position: i32,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
@@ -0,0 +1,23 @@
+use super::{MapType, PickType, SpectatorType, SummonerId, TournamentRegion};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentCode {
+    pub code: String,
+    pub spectators: SpectatorType,
+    pub lobby_name: String,
+    pub meta_data: Option<String>,
+    pub password: String,
+    pub team_size: i32,
+    pub provider_id: i32,
+    pub pick_type: PickType,
+    pub tournament_id: i32,
+    pub id: i32,
+    pub region: TournamentRegion,
+    pub map: MapType,
+    pub participants: Vec<SummonerId>,
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::ExtraFields,
+}
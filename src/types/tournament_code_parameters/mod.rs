@@ -0,0 +1,15 @@
+use super::{MapType, PickType, SpectatorType, SummonerId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentCodeParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_summoner_ids: Option<Vec<SummonerId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+    pub team_size: i32,
+    pub pick_type: PickType,
+    pub map_type: MapType,
+    pub spectator_type: SpectatorType,
+}
@@ -0,0 +1,15 @@
+use super::{MapType, PickType, SpectatorType, SummonerId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentCodeUpdateParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_summoner_ids: Option<Vec<SummonerId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pick_type: Option<PickType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub map_type: Option<MapType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spectator_type: Option<SpectatorType>,
+}
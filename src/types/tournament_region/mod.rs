@@ -0,0 +1,28 @@
+fallback_enum! {
+    pub enum TournamentRegion {
+        #[strum(serialize = "BR")]
+        Br,
+        #[strum(serialize = "EUNE")]
+        Eune,
+        #[strum(serialize = "EUW")]
+        Euw,
+        #[strum(serialize = "JP")]
+        Jp,
+        #[strum(serialize = "LAN")]
+        Lan,
+        #[strum(serialize = "LAS")]
+        Las,
+        #[strum(serialize = "NA")]
+        Na,
+        #[strum(serialize = "OCE")]
+        Oce,
+        #[strum(serialize = "PBE")]
+        Pbe,
+        #[strum(serialize = "RU")]
+        Ru,
+        #[strum(serialize = "TR")]
+        Tr,
+        #[strum(serialize = "KR")]
+        Kr,
+    }
+}
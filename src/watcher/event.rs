@@ -0,0 +1,44 @@
+use crate::{
+    enums::{Queue, Region},
+    rank::Rank,
+    types::{MatchReference, SummonerId},
+};
+
+/// A change in the activity of a watched summoner, emitted by `Watcher::events()`.
+#[derive(Clone, Debug)]
+pub struct WatchEvent {
+    pub region: Region,
+    pub summoner_id: SummonerId,
+    pub kind: WatchEventKind,
+}
+
+/// What changed for a watched summoner.
+#[derive(Clone, Debug)]
+pub enum WatchEventKind {
+    /// A match played since the summoner was last polled, emitted oldest first.
+    NewMatch(MatchReference),
+    /// The rank of the summoner in a queue changed, `None` while unranked in that queue.
+    RankChanged {
+        queue: Queue,
+        previous: Option<Rank>,
+        current: Option<Rank>,
+    },
+    NameChanged {
+        previous: String,
+        current: String,
+    },
+    LevelChanged {
+        previous: i32,
+        current: i32,
+    },
+}
+
+impl WatchEventKind {
+    /// Whether the event shows the summoner has been playing, which makes the watcher poll them more often.
+    pub fn is_activity(&self) -> bool {
+        matches!(
+            self,
+            WatchEventKind::NewMatch(_) | WatchEventKind::RankChanged { .. }
+        )
+    }
+}
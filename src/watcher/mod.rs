@@ -0,0 +1,443 @@
+//! Contains the `Watcher`, which polls registered summoners and emits a stream of `WatchEvent`s for new matches, rank
+//! changes and name or level changes.
+
+mod event;
+mod state;
+
+pub use event::{WatchEvent, WatchEventKind};
+pub use state::{WatchedSummoner, WatcherState};
+
+use crate::{
+    enums::Region,
+    proxy::{Result, MIN_RETRY_AFTER},
+    types::SummonerId,
+    Client, Error,
+};
+use futures_util::{
+    future::{select, Either},
+    stream::{self, BoxStream, StreamExt},
+};
+use log::debug;
+use state::PollUpdate;
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant, SystemTime},
+};
+use tokio::sync::mpsc::{
+    unbounded_channel, UnboundedReceiver, UnboundedSender, WeakUnboundedSender,
+};
+
+/// The number of requests made to poll a single summoner: the summoner, their match list and their league entries.
+pub const REQUESTS_PER_POLL: u32 = 3;
+
+/// How often a `Watcher` polls and how many requests it may make.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::{watcher::WatcherOptions, Error};
+/// # use std::time::Duration;
+/// let options = WatcherOptions::default();
+/// let minutes = |minutes: u64| Duration::from_secs(minutes * 60);
+///
+/// // Idle summoners are polled half as often after every poll, up to the maximum interval.
+/// assert_eq!(options.next_poll_interval(minutes(2), false), minutes(4));
+/// assert_eq!(options.next_poll_interval(minutes(45), false), minutes(60));
+///
+/// // Activity resets the interval.
+/// assert_eq!(options.next_poll_interval(minutes(60), true), minutes(2));
+///
+/// // 30 requests per minute allow a poll of 3 requests every 6 seconds.
+/// assert_eq!(options.poll_spacing(), Duration::from_secs(6));
+///
+/// // Being rate limited pauses polling for as long as the API asked.
+/// assert_eq!(
+///     options.pause_after(&Error::TooManyRequests(Duration::from_secs(10))),
+///     Some(Duration::from_secs(10)),
+/// );
+/// assert_eq!(options.pause_after(&Error::NotFound), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct WatcherOptions {
+    /// The maximum number of requests made per minute across all watched summoners.
+    pub requests_per_minute: u32,
+    /// The time between polls of a summoner that has just been active.
+    pub min_poll_interval: Duration,
+    /// The longest time between polls of an idle summoner.
+    pub max_poll_interval: Duration,
+}
+
+impl WatcherOptions {
+    /// The time until the next poll of a summoner, given the current interval and whether the last poll found a new
+    /// match or rank change.
+    pub fn next_poll_interval(&self, current: Duration, active: bool) -> Duration {
+        if active {
+            self.min_poll_interval
+        } else {
+            (current * 2)
+                .max(self.min_poll_interval)
+                .min(self.max_poll_interval)
+        }
+    }
+
+    /// The shortest time between two polls that stays within `requests_per_minute`.
+    pub fn poll_spacing(&self) -> Duration {
+        Duration::from_secs(60) * REQUESTS_PER_POLL / self.requests_per_minute.max(1)
+    }
+
+    /// How long to stop polling after a poll failed with `error`, if at all.
+    pub fn pause_after(&self, error: &Error) -> Option<Duration> {
+        match error {
+            Error::TooManyRequests(duration) => Some((*duration).max(MIN_RETRY_AFTER)),
+            _ => None,
+        }
+    }
+}
+
+impl Default for WatcherOptions {
+    /// 30 requests per minute, polling each summoner every 2 minutes to every hour.
+    fn default() -> Self {
+        WatcherOptions {
+            requests_per_minute: 30,
+            min_poll_interval: Duration::from_secs(2 * 60),
+            max_poll_interval: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+impl Client {
+    /// Constructs a watcher without any watched summoners.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use league_of_legends_client::{watcher::WatcherOptions, Client, ClientOptions};
+    /// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    ///
+    /// let watcher = client.watcher(WatcherOptions::default());
+    /// ```
+    pub fn watcher(&self, options: WatcherOptions) -> Watcher<'_> {
+        self.watcher_with_state(options, WatcherState::default())
+    }
+
+    /// Constructs a watcher resuming from a previously saved `WatcherState`.
+    pub fn watcher_with_state(&self, options: WatcherOptions, state: WatcherState) -> Watcher<'_> {
+        let (sender, commands) = unbounded_channel();
+
+        Watcher {
+            client: self,
+            options,
+            state,
+            next_request_at: Instant::now(),
+            sender: sender.downgrade(),
+            commands,
+        }
+    }
+}
+
+/// A change to the watched summoners sent through a `WatcherHandle`.
+enum Command {
+    Watch(Region, SummonerId),
+    Unwatch(Region, SummonerId),
+}
+
+/// Registers and removes summoners of a `Watcher` while its event stream is running.
+///
+/// Obtained through `watcher.handle()`. Changes are picked up before the next poll, even while the stream is waiting
+/// for a summoner to be due. While a handle exists the stream keeps running without watched summoners, waiting for
+/// one to be registered.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::Region,
+/// #    types::SummonerId,
+/// #    watcher::WatcherOptions,
+/// #    Client, ClientOptions,
+/// # };
+/// use futures_util::StreamExt;
+/// #
+/// # #[tokio::main]
+/// # async fn main() {
+/// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+/// let mut watcher = client.watcher(WatcherOptions::default());
+/// let handle = watcher.handle();
+/// let mut events = watcher.events();
+///
+/// handle.watch(Region::NorthAmerica, SummonerId::from("SUMMONER_ID"));
+///
+/// // The summoner is polled, which fails with the example API key.
+/// assert!(events.next().await.unwrap().is_err());
+///
+/// handle.unwatch(Region::NorthAmerica, SummonerId::from("SUMMONER_ID"));
+///
+/// // The stream ends once no summoners are watched and every handle is dropped.
+/// drop(handle);
+/// assert!(events.next().await.is_none());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct WatcherHandle {
+    sender: UnboundedSender<Command>,
+}
+
+impl WatcherHandle {
+    /// Starts watching a summoner, see `Watcher::watch`.
+    pub fn watch(&self, region: Region, summoner_id: SummonerId) {
+        let _ = self.sender.send(Command::Watch(region, summoner_id));
+    }
+
+    /// Stops watching a summoner, see `Watcher::unwatch`.
+    pub fn unwatch(&self, region: Region, summoner_id: SummonerId) {
+        let _ = self.sender.send(Command::Unwatch(region, summoner_id));
+    }
+}
+
+/// Polls registered summoners for new matches, rank changes and name or level changes.
+///
+/// Each summoner is polled on its own schedule: after a new match or rank change it is polled again after
+/// `min_poll_interval`, and every idle poll doubles the interval up to `max_poll_interval`. Polls are spaced out so all
+/// summoners together stay within `requests_per_minute`, and being rate limited pauses polling for the duration the
+/// API asked for. See `WatcherOptions` for the exact schedule.
+///
+/// Do not create this struct manually. The preferred method is to call `client.watcher(...)`.
+///
+/// # Example
+///
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::Region,
+/// #    types::SummonerId,
+/// #    watcher::{WatchEventKind, WatcherOptions},
+/// #    Client, ClientOptions,
+/// #    Error,
+/// # };
+/// use futures_util::StreamExt;
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+/// let mut watcher = client.watcher(WatcherOptions::default());
+///
+/// watcher.watch(Region::NorthAmerica, SummonerId::from("SUMMONER_ID"));
+///
+/// let mut events = watcher.events();
+///
+/// # // Only take the first event, the stream never ends while summoners are watched.
+/// # if let Some(event) = events.next().await {
+/// match event {
+///     Ok(event) => match event.kind {
+///         WatchEventKind::NewMatch(reference) => println!("{} played {}", event.summoner_id, reference.game_id),
+///         kind => println!("{} changed: {:?}", event.summoner_id, kind),
+///     },
+///     Err(error) => println!("Oh no! An error occurred! Error: {:#?}", error),
+/// }
+/// # }
+///
+/// // Save the state to resume watching later, events that were not yielded yet are reported again.
+/// drop(events);
+/// let saved = serde_json::to_string(watcher.state()).unwrap();
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct Watcher<'a> {
+    client: &'a Client,
+    pub options: WatcherOptions,
+    state: WatcherState,
+    next_request_at: Instant,
+    /// The sender of handles, weak so the channel closes once every handle is dropped.
+    sender: WeakUnboundedSender<Command>,
+    commands: UnboundedReceiver<Command>,
+}
+
+impl Watcher<'_> {
+    /// Starts watching a summoner, returning false if they are already watched.
+    ///
+    /// Only matches created after this call are reported.
+    pub fn watch(&mut self, region: Region, summoner_id: SummonerId) -> bool {
+        if self.position(region, &summoner_id).is_some() {
+            return false;
+        }
+
+        self.state.summoners.push(WatchedSummoner::new(
+            region,
+            summoner_id,
+            self.options.min_poll_interval,
+        ));
+
+        true
+    }
+
+    /// Stops watching a summoner, returning their last seen state.
+    pub fn unwatch(&mut self, region: Region, summoner_id: &SummonerId) -> Option<WatchedSummoner> {
+        self.position(region, summoner_id)
+            .map(|index| self.state.summoners.remove(index))
+    }
+
+    /// A handle to watch and unwatch summoners while the stream returned by `events()` is running.
+    pub fn handle(&mut self) -> WatcherHandle {
+        let sender = match self.sender.upgrade() {
+            Some(sender) => sender,
+            None => {
+                // Every previous handle was dropped, run their last commands before replacing the closed channel.
+                while let Ok(command) = self.commands.try_recv() {
+                    self.run(command);
+                }
+
+                let (sender, commands) = unbounded_channel();
+                self.sender = sender.downgrade();
+                self.commands = commands;
+                sender
+            }
+        };
+
+        WatcherHandle { sender }
+    }
+
+    /// The last seen state of the watched summoners, which can be saved to resume watching later.
+    pub fn state(&self) -> &WatcherState {
+        &self.state
+    }
+
+    /// A stream of the events of all watched summoners, polling each summoner when they are due.
+    ///
+    /// The state of a summoner only changes when one of their events is yielded, so events that were found but not
+    /// yielded before the stream is dropped are reported again by the next poll. Failing to poll a summoner yields
+    /// the error and polls them again later. The stream only ends once no summoners are watched and no
+    /// `WatcherHandle` is left to register one.
+    pub fn events(&mut self) -> BoxStream<'_, Result<WatchEvent>> {
+        stream::unfold(
+            (self, VecDeque::new()),
+            |(watcher, mut pending)| async move {
+                loop {
+                    if let Some(event) = pending.pop_front() {
+                        if let Ok(event) = &event {
+                            watcher.apply(event);
+                        }
+
+                        return Some((event, (watcher, pending)));
+                    }
+
+                    while let Ok(command) = watcher.commands.try_recv() {
+                        watcher.run(command);
+                    }
+
+                    let index = match watcher.next_due() {
+                        Some(index) => index,
+                        // Wait for a handle to register a summoner, ending once every handle is dropped.
+                        None => {
+                            let command = watcher.commands.recv().await?;
+                            watcher.run(command);
+                            continue;
+                        }
+                    };
+
+                    // Wake up for the due summoner, or earlier for a command from a handle.
+                    let command = {
+                        let sleep = Box::pin(tokio::time::sleep(watcher.time_until_due(index)));
+                        let command = Box::pin(watcher.commands.recv());
+
+                        match select(sleep, command).await {
+                            Either::Left(_) => None,
+                            Either::Right((Some(command), _)) => Some(command),
+                            // Every handle was dropped, only the due summoner can wake the stream.
+                            Either::Right((None, sleep)) => {
+                                sleep.await;
+                                None
+                            }
+                        }
+                    };
+
+                    match command {
+                        Some(command) => watcher.run(command),
+                        None => pending.extend(watcher.poll(index).await),
+                    }
+                }
+            },
+        )
+        .boxed()
+    }
+
+    fn run(&mut self, command: Command) {
+        match command {
+            Command::Watch(region, summoner_id) => {
+                self.watch(region, summoner_id);
+            }
+            Command::Unwatch(region, summoner_id) => {
+                self.unwatch(region, &summoner_id);
+            }
+        }
+    }
+
+    fn position(&self, region: Region, summoner_id: &SummonerId) -> Option<usize> {
+        self.state
+            .summoners
+            .iter()
+            .position(|summoner| summoner.region == region && &summoner.summoner_id == summoner_id)
+    }
+
+    /// Records a yielded event in the state of its summoner, unless they are no longer watched.
+    fn apply(&mut self, event: &WatchEvent) {
+        if let Some(index) = self.position(event.region, &event.summoner_id) {
+            self.state.summoners[index].apply(&event.kind);
+        }
+    }
+
+    /// The index of the summoner polled next.
+    fn next_due(&self) -> Option<usize> {
+        self.state
+            .summoners
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, summoner)| summoner.next_poll_at)
+            .map(|(index, _)| index)
+    }
+
+    /// The time until the summoner is due and the request budget allows another poll.
+    fn time_until_due(&self, index: usize) -> Duration {
+        let until_due = self.state.summoners[index]
+            .next_poll_at
+            .duration_since(SystemTime::now())
+            .unwrap_or_default();
+        let until_budget = self
+            .next_request_at
+            .saturating_duration_since(Instant::now());
+
+        until_due.max(until_budget)
+    }
+
+    /// Polls the summoner and schedules their next poll, returning the events to yield.
+    async fn poll(&mut self, index: usize) -> Vec<Result<WatchEvent>> {
+        let mut update = PollUpdate::default();
+        let result = self.state.summoners[index]
+            .poll(self.client, &mut update)
+            .await;
+
+        let summoner = &mut self.state.summoners[index];
+        let events = summoner.record(update);
+        let active = events.iter().any(|event| event.kind.is_activity());
+
+        summoner.poll_interval = self
+            .options
+            .next_poll_interval(summoner.poll_interval, active);
+        summoner.next_poll_at = SystemTime::now() + summoner.poll_interval;
+        self.next_request_at = Instant::now() + self.options.poll_spacing();
+
+        let mut results: Vec<Result<WatchEvent>> = events.into_iter().map(Ok).collect();
+
+        if let Err(error) = result {
+            if let Some(pause) = self.options.pause_after(&error) {
+                debug!(
+                    "Rate limited while watching summoners, pausing for {:?}.",
+                    pause
+                );
+                self.next_request_at = self.next_request_at.max(Instant::now() + pause);
+            }
+
+            results.push(Err(error));
+        }
+
+        results
+    }
+}
@@ -0,0 +1,325 @@
+use super::{WatchEvent, WatchEventKind};
+use crate::{
+    enums::{Queue, Region},
+    proxy::Result,
+    rank::Rank,
+    types::{AccountId, SummonerId},
+    Client, Error,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
+
+/// The last seen state of every summoner registered with a `Watcher`.
+///
+/// The state can be serialized and passed to `client.watcher_with_state(...)` later, so matches and changes that
+/// happened while the watcher was not running are still reported.
+///
+/// # Example
+/// ```rust
+/// # use league_of_legends_client::{
+/// #    enums::Region,
+/// #    types::SummonerId,
+/// #    watcher::{WatcherOptions, WatcherState},
+/// #    Client, ClientOptions,
+/// # };
+/// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+/// let mut watcher = client.watcher(WatcherOptions::default());
+///
+/// watcher.watch(Region::NorthAmerica, SummonerId::from("SUMMONER_ID"));
+///
+/// let saved = serde_json::to_string(watcher.state()).unwrap();
+/// let state: WatcherState = serde_json::from_str(&saved).unwrap();
+/// let watcher = client.watcher_with_state(WatcherOptions::default(), state);
+///
+/// assert_eq!(watcher.state().summoners.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct WatcherState {
+    pub summoners: Vec<WatchedSummoner>,
+}
+
+/// The last seen state of a single watched summoner.
+///
+/// Fields that are only known after the first poll are `None` until then.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WatchedSummoner {
+    pub region: Region,
+    pub summoner_id: SummonerId,
+    pub account_id: Option<AccountId>,
+    pub name: Option<String>,
+    pub level: Option<i32>,
+    /// The rank in each queue the summoner is ranked in.
+    pub ranks: HashMap<Queue, Rank>,
+    /// When the summoner was registered, matches created before are not reported.
+    pub watched_since: SystemTime,
+    /// The creation time of the newest match reported.
+    pub last_match_time: Option<SystemTime>,
+    pub last_polled_at: Option<SystemTime>,
+    pub next_poll_at: SystemTime,
+    /// The current time between polls, which shrinks after activity and grows while the summoner is idle.
+    pub poll_interval: Duration,
+}
+
+/// What a single poll found.
+///
+/// The account id and the values seen for the first time are recorded right away by `WatchedSummoner::record`, while
+/// each event only changes the state once it is yielded, through `WatchedSummoner::apply`.
+#[derive(Default)]
+pub(crate) struct PollUpdate {
+    account_id: Option<AccountId>,
+    name: Option<String>,
+    level: Option<i32>,
+    ranks: Option<HashMap<Queue, Rank>>,
+    polled_at: Option<SystemTime>,
+    events: Vec<WatchEvent>,
+}
+
+impl PollUpdate {
+    fn push(&mut self, summoner: &WatchedSummoner, kind: WatchEventKind) {
+        self.events.push(WatchEvent {
+            region: summoner.region,
+            summoner_id: summoner.summoner_id.clone(),
+            kind,
+        });
+    }
+}
+
+impl WatchedSummoner {
+    pub(crate) fn new(region: Region, summoner_id: SummonerId, poll_interval: Duration) -> Self {
+        let now = SystemTime::now();
+
+        WatchedSummoner {
+            region,
+            summoner_id,
+            account_id: None,
+            name: None,
+            level: None,
+            ranks: HashMap::new(),
+            watched_since: now,
+            last_match_time: None,
+            last_polled_at: None,
+            next_poll_at: now,
+            poll_interval,
+        }
+    }
+
+    /// Records an event as seen, so it is not reported again.
+    ///
+    /// The `Watcher` applies each event as it is yielded, call this only when managing a `WatcherState` yourself.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use league_of_legends_client::{
+    /// #    enums::{Division, Queue, Region, Tier},
+    /// #    rank::Rank,
+    /// #    types::{MatchReference, SummonerId},
+    /// #    watcher::{WatchEventKind, WatcherOptions},
+    /// #    Client, ClientOptions,
+    /// # };
+    /// # let reference: MatchReference = serde_json::from_value(serde_json::json!({
+    /// #     "gameId": 3700000000i64, "role": "SOLO", "season": 13, "platformId": "NA1", "champion": 86,
+    /// #     "queue": 420, "lane": "TOP", "timestamp": 1610000000000i64
+    /// # })).unwrap();
+    /// let client = Client::new(ClientOptions::new("EXAMPLE_API_KEY", false));
+    /// let mut watcher = client.watcher(WatcherOptions::default());
+    ///
+    /// watcher.watch(Region::NorthAmerica, SummonerId::from("SUMMONER_ID"));
+    ///
+    /// let mut summoner = watcher.state().summoners[0].clone();
+    /// let rank = Rank::new(Tier::Gold, Division::Two, 40);
+    ///
+    /// summoner.apply(&WatchEventKind::NewMatch(reference.clone()));
+    /// summoner.apply(&WatchEventKind::LevelChanged { previous: 30, current: 31 });
+    /// summoner.apply(&WatchEventKind::RankChanged {
+    ///     queue: Queue::RankedSoloQueue,
+    ///     previous: None,
+    ///     current: Some(rank.clone()),
+    /// });
+    ///
+    /// assert_eq!(summoner.last_match_time, Some(reference.creation_time()));
+    /// assert_eq!(summoner.level, Some(31));
+    /// assert_eq!(summoner.ranks[&Queue::RankedSoloQueue], rank);
+    ///
+    /// summoner.apply(&WatchEventKind::RankChanged {
+    ///     queue: Queue::RankedSoloQueue,
+    ///     previous: Some(rank),
+    ///     current: None,
+    /// });
+    ///
+    /// assert!(summoner.ranks.is_empty());
+    /// ```
+    pub fn apply(&mut self, kind: &WatchEventKind) {
+        match kind {
+            WatchEventKind::NewMatch(reference) => {
+                let created = reference.creation_time();
+
+                self.last_match_time = Some(
+                    self.last_match_time
+                        .map_or(created, |last_match_time| last_match_time.max(created)),
+                );
+            }
+            WatchEventKind::RankChanged {
+                queue,
+                current: Some(rank),
+                ..
+            } => {
+                self.ranks.insert(queue.clone(), rank.clone());
+            }
+            WatchEventKind::RankChanged {
+                queue,
+                current: None,
+                ..
+            } => {
+                self.ranks.remove(queue);
+            }
+            WatchEventKind::NameChanged { current, .. } => self.name = Some(current.clone()),
+            WatchEventKind::LevelChanged { current, .. } => self.level = Some(*current),
+        }
+    }
+
+    /// Records what a poll found apart from its events, which are returned to be applied as they are yielded.
+    pub(crate) fn record(&mut self, update: PollUpdate) -> Vec<WatchEvent> {
+        let PollUpdate {
+            account_id,
+            name,
+            level,
+            ranks,
+            polled_at,
+            events,
+        } = update;
+
+        if account_id.is_some() {
+            self.account_id = account_id;
+        }
+
+        if name.is_some() {
+            self.name = name;
+        }
+
+        if level.is_some() {
+            self.level = level;
+        }
+
+        if let Some(ranks) = ranks {
+            self.ranks = ranks;
+        }
+
+        if polled_at.is_some() {
+            self.last_polled_at = polled_at;
+        }
+
+        events
+    }
+
+    /// Requests the summoner, their new matches and their league entries, collecting the changes since the last seen
+    /// state in `update` without changing it.
+    ///
+    /// The first poll only collects the name, level and ranks of the summoner. Changes found before an error are
+    /// still collected.
+    pub(crate) async fn poll(&self, client: &Client, update: &mut PollUpdate) -> Result<()> {
+        let polled_at = SystemTime::now();
+
+        let summoner = client
+            .summoner()
+            .get_summoner_by_encrypted_summoner_id(self.region, self.summoner_id.clone())
+            .await?;
+
+        update.account_id = Some(summoner.account_id.clone());
+
+        match &self.name {
+            None => update.name = Some(summoner.name.clone()),
+            Some(previous) if previous != &summoner.name => update.push(
+                self,
+                WatchEventKind::NameChanged {
+                    previous: previous.clone(),
+                    current: summoner.name.clone(),
+                },
+            ),
+            Some(_) => {}
+        }
+
+        match self.level {
+            None => update.level = Some(summoner.summoner_level),
+            Some(previous) if previous != summoner.summoner_level => update.push(
+                self,
+                WatchEventKind::LevelChanged {
+                    previous,
+                    current: summoner.summoner_level,
+                },
+            ),
+            Some(_) => {}
+        }
+
+        let since = self.last_match_time.unwrap_or(self.watched_since);
+        let match_list = client
+            .r#match()
+            .get_match_list_by_account(
+                self.region,
+                summoner.account_id,
+                None,
+                None,
+                None,
+                Some(since + Duration::from_millis(1)),
+                None,
+                None,
+            )
+            .await;
+
+        match match_list {
+            Ok(match_list) => {
+                let mut matches: Vec<_> = match_list
+                    .matches
+                    .into_iter()
+                    .filter(|reference| reference.creation_time() > since)
+                    .collect();
+                matches.sort_by_key(|reference| reference.timestamp);
+
+                for reference in matches {
+                    update.push(self, WatchEventKind::NewMatch(reference));
+                }
+            }
+            // The match list responds with not found when there are no matches in the time range.
+            Err(Error::NotFound) => {}
+            Err(error) => return Err(error),
+        }
+
+        let ranks: HashMap<Queue, Rank> = client
+            .league()
+            .get_league_entries_for_summoner(self.region, self.summoner_id.clone())
+            .await?
+            .iter()
+            .map(|entry| (entry.queue_type.clone(), Rank::from(entry)))
+            .collect();
+
+        if self.last_polled_at.is_none() {
+            update.ranks = Some(ranks);
+        } else {
+            let mut queues: Vec<&Queue> = self.ranks.keys().chain(ranks.keys()).collect();
+            queues.sort_by_key(|queue| queue.to_string());
+            queues.dedup();
+
+            for queue in queues {
+                let previous = self.ranks.get(queue);
+                let current = ranks.get(queue);
+
+                if previous != current {
+                    update.push(
+                        self,
+                        WatchEventKind::RankChanged {
+                            queue: queue.clone(),
+                            previous: previous.cloned(),
+                            current: current.cloned(),
+                        },
+                    );
+                }
+            }
+        }
+
+        update.polled_at = Some(polled_at);
+
+        Ok(())
+    }
+}